@@ -1,12 +1,28 @@
+use crate::error::Error;
+use crate::theme::components::{ImageCharset, ASCII};
 use crate::Result;
 use ansi_to_tui::IntoText;
+use atty::Stream;
 use colored::Colorize;
+use image::imageops::FilterType;
+use image::{DynamicImage, Rgba, RgbaImage};
 use io::Read;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ratatui::crossterm::style::{ResetColor, SetForegroundColor};
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span, Text};
+use regex::bytes;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
 use std::fs::File;
 use std::io::{self, BufReader};
 use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
 
 lazy_static! {
     static ref BLUE: Style = Style::default().fg(Color::Blue);
@@ -18,72 +34,538 @@ lazy_static! {
     static ref BLACK: Style = Style::default().fg(Color::Black);
 }
 
+/// Credit for the ASCII art that was drawn for macchina, rather than taken
+/// from an outside artist.
+const ORIGINAL: &str = "original art, drawn for macchina";
+
+#[derive(Debug, Clone, Copy)]
 pub enum AsciiSize {
     Big,
     Small,
 }
 
+impl fmt::Display for AsciiSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AsciiSize::Big => write!(f, "big"),
+            AsciiSize::Small => write!(f, "small"),
+        }
+    }
+}
+
+/// A built-in ASCII art variant along with a credit for its artist.
+#[derive(Debug, Clone)]
+pub struct AsciiArt {
+    text: Text<'static>,
+    credit: &'static str,
+}
+
+impl AsciiArt {
+    fn new<T: Into<Text<'static>>>(text: T, credit: &'static str) -> AsciiArt {
+        AsciiArt {
+            text: text.into(),
+            credit,
+        }
+    }
+}
+
+/// Determines which of the built-in ASCII art variants gets displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AsciiSelect {
+    #[default]
+    First,
+    Random,
+    Index(usize),
+}
+
+impl Serialize for AsciiSelect {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AsciiSelect::First => serializer.serialize_str("first"),
+            AsciiSelect::Random => serializer.serialize_str("random"),
+            AsciiSelect::Index(i) => serializer.serialize_u64(*i as u64),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AsciiSelect {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AsciiSelectVisitor;
+
+        impl Visitor<'_> for AsciiSelectVisitor {
+            type Value = AsciiSelect;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("\"first\", \"random\" or the index of an ASCII art")
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<AsciiSelect, E>
+            where
+                E: de::Error,
+            {
+                match value.to_lowercase().as_str() {
+                    "first" => Ok(AsciiSelect::First),
+                    "random" => Ok(AsciiSelect::Random),
+                    _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+                }
+            }
+
+            fn visit_u64<E>(self, value: u64) -> std::result::Result<AsciiSelect, E>
+            where
+                E: de::Error,
+            {
+                Ok(AsciiSelect::Index(value as usize))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> std::result::Result<AsciiSelect, E>
+            where
+                E: de::Error,
+            {
+                usize::try_from(value)
+                    .map(AsciiSelect::Index)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+        }
+
+        deserializer.deserialize_any(AsciiSelectVisitor)
+    }
+}
+
+/// The `[ascii]` table of the configuration file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AsciiOptions {
+    pub select: AsciiSelect,
+    pub seed: Option<u64>,
+}
+
 pub fn list_ascii_artists() {
     println!(
-        "- FreeBSD ASCII art (small variant) was taken from {}' {}",
+        "- FreeBSD ASCII art (small variant #0) was taken from {}' {}",
         "Dylan Araps".bold(),
         "pfetch".bright_purple()
     );
 
     println!(
-        "- macOS ASCII art (big variant) was taken from {}' {}",
+        "- macOS ASCII art (big variant #0) was taken from {}' {}",
         "Dylan Araps".bold(),
         "Neofetch".bright_purple()
     );
 
     println!(
-        "- macOS ASCII art (small variant) was originally made by {}",
+        "- macOS ASCII art (small variant #0) was originally made by {}",
         "Joan Stark".bold(),
     );
 
     println!(
-        "- Linux ASCII art (big variant) was originally made by {}",
+        "- Linux ASCII art (big variant #0) was originally made by {}",
         "Joan Stark".bold(),
     );
 
     println!(
-        "- Linux ASCII art (small variant) was taken from {}",
+        "- Linux ASCII art (small variant #0) was taken from {}",
         "Christopher Johnson's ASCII art collection".bold(),
     );
+
+    println!(
+        "- Variants that {} credits as original art were drawn specifically for macchina",
+        "--list-ascii".bright_purple(),
+    );
 }
 
-pub fn select_ascii(ascii_size: AsciiSize) -> Option<Text<'static>> {
+/// Prints every built-in ASCII art variant of the current platform along
+/// with its index and the credit of its artist.
+pub fn list_ascii() {
+    for size in [AsciiSize::Big, AsciiSize::Small] {
+        for (index, art) in get_ascii_art(size).iter().enumerate() {
+            println!("{}: {}", format!("{size} #{index}").bold(), art.credit);
+
+            for line in art.text.lines.iter() {
+                for span in line.spans.iter() {
+                    match span.style.fg {
+                        Some(color) => print!(
+                            "{}{}{}",
+                            SetForegroundColor(color.into()),
+                            span.content,
+                            ResetColor
+                        ),
+                        None => print!("{}", span.content),
+                    }
+                }
+                println!();
+            }
+
+            println!();
+        }
+    }
+}
+
+pub fn select_ascii(ascii_size: AsciiSize, options: &AsciiOptions) -> Option<Text<'static>> {
     let ascii_art = get_ascii_art(ascii_size);
 
     if ascii_art.is_empty() {
         return None;
     }
 
-    Some(ascii_art[0].to_owned())
+    let index = match options.select {
+        AsciiSelect::First => 0,
+        AsciiSelect::Random => match options.seed {
+            Some(seed) => StdRng::seed_from_u64(seed).gen_range(0..ascii_art.len()),
+            None => rand::thread_rng().gen_range(0..ascii_art.len()),
+        },
+        // an out-of-range index, which is reported by `check_selection`,
+        // falls back to the first variant.
+        AsciiSelect::Index(i) if i < ascii_art.len() => i,
+        AsciiSelect::Index(_) => 0,
+    };
+
+    Some(ascii_art[index].text.to_owned())
 }
 
-pub fn get_ascii_from_file(file_path: &Path) -> Result<Text<'static>> {
-    let file = File::open(file_path)?;
-    let mut buffer = Vec::new();
-    let mut reader = BufReader::new(file);
-    reader.read_to_end(&mut buffer)?;
-    Ok(buffer.into_text().unwrap_or_default())
+/// Checks that the selected index refers to a built-in ASCII art variant of
+/// both sizes.
+pub fn check_selection(options: &AsciiOptions) -> Result<()> {
+    let AsciiSelect::Index(index) = options.select else {
+        return Ok(());
+    };
+
+    let count = get_ascii_art(AsciiSize::Big)
+        .len()
+        .min(get_ascii_art(AsciiSize::Small).len());
+
+    // platforms without built-in ASCII art have nothing to select.
+    if index < count || count == 0 {
+        return Ok(());
+    }
+
+    Err(Error::Ascii(format!(
+        "There is no built-in ASCII art variant #{index}, as the index must range \
+         from 0 to {}; see --list-ascii.",
+        count - 1
+    )))
+}
+
+lazy_static! {
+    static ref COLOR_PLACEHOLDER: bytes::Regex = bytes::Regex::new(r"\$\{c(\d+)\}").unwrap();
 }
 
-pub fn get_ascii_from_file_override_color(file_path: &Path, color: Color) -> Result<Text<'static>> {
+/// Replaces the neofetch-style `${c1}`..`${c6}` placeholders of an ASCII art
+/// with the escape sequences of the corresponding colors, which are then
+/// picked up by `into_text`. Placeholders without a matching color reset it.
+fn fill_color_placeholders(ascii: &[u8], colors: &[Color]) -> Vec<u8> {
+    COLOR_PLACEHOLDER
+        .replace_all(ascii, |caps: &bytes::Captures| {
+            let color = std::str::from_utf8(&caps[1])
+                .ok()
+                .and_then(|i| i.parse::<usize>().ok())
+                .and_then(|i| i.checked_sub(1))
+                .and_then(|i| colors.get(i))
+                .copied()
+                .unwrap_or(Color::Reset);
+
+            format!("\x1b[{}m", color_to_sgr(color)).into_bytes()
+        })
+        .into_owned()
+}
+
+/// Removes the `${c1}`..`${c6}` placeholders of an ASCII art, so that they
+/// don't override the color it's painted with.
+fn strip_color_placeholders(ascii: &[u8]) -> Vec<u8> {
+    COLOR_PLACEHOLDER.replace_all(ascii, &b""[..]).into_owned()
+}
+
+/// Returns the SGR parameters that set the foreground to the given color.
+fn color_to_sgr(color: Color) -> String {
+    match color {
+        Color::Reset => String::from("39"),
+        Color::Black => String::from("30"),
+        Color::Red => String::from("31"),
+        Color::Green => String::from("32"),
+        Color::Yellow => String::from("33"),
+        Color::Blue => String::from("34"),
+        Color::Magenta => String::from("35"),
+        Color::Cyan => String::from("36"),
+        Color::Gray => String::from("37"),
+        Color::DarkGray => String::from("90"),
+        Color::LightRed => String::from("91"),
+        Color::LightGreen => String::from("92"),
+        Color::LightYellow => String::from("93"),
+        Color::LightBlue => String::from("94"),
+        Color::LightMagenta => String::from("95"),
+        Color::LightCyan => String::from("96"),
+        Color::White => String::from("97"),
+        Color::Indexed(i) => format!("38;5;{i}"),
+        Color::Rgb(r, g, b) => format!("38;2;{r};{g};{b}"),
+    }
+}
+
+pub fn read_ascii_file(file_path: &Path) -> Result<Vec<u8>> {
     let file = File::open(file_path)?;
     let mut reader = BufReader::new(file);
     let mut buffer: Vec<u8> = Vec::new();
     reader.read_to_end(&mut buffer)?;
-    let text = buffer.into_text().unwrap_or_default();
-    let text = text.patch_style(Style::default().fg(color));
-    Ok(text)
+    Ok(buffer)
+}
+
+pub fn read_ascii_stdin() -> Result<Vec<u8>> {
+    // reading from a terminal would block until the user sends EOF.
+    if atty::is(Stream::Stdin) {
+        return Err(
+            io::Error::new(io::ErrorKind::InvalidInput, "nothing was piped to stdin").into(),
+        );
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+    io::stdin().lock().read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Runs the given command through the shell and returns its output, which
+/// fails if the command is unsuccessful or doesn't exit within `timeout`.
+pub fn read_ascii_command(command: &str, timeout: Duration) -> Result<Vec<u8>> {
+    #[cfg(not(target_os = "windows"))]
+    let mut shell = Command::new("sh");
+    #[cfg(not(target_os = "windows"))]
+    shell.args(["-c", command]);
+
+    #[cfg(target_os = "windows")]
+    let mut shell = Command::new("cmd");
+    #[cfg(target_os = "windows")]
+    shell.args(["/C", command]);
+
+    let mut child = shell
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // the output is read from another thread so that a command filling up the
+    // pipe doesn't block while we're waiting for it to exit. It's received
+    // over a channel, as processes spawned by the command in the background
+    // can keep the pipe open long after the command itself has exited.
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buffer: Vec<u8> = Vec::new();
+        let _ = sender.send(stdout.read_to_end(&mut buffer).map(|_| buffer));
+    });
+
+    let timed_out = || {
+        let message = format!("\"{command}\" timed out");
+        io::Error::new(io::ErrorKind::TimedOut, message)
+    };
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        if start.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Err(timed_out().into());
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    if !status.success() {
+        let message = format!("\"{command}\" exited with {status}");
+        return Err(io::Error::other(message).into());
+    }
+
+    match receiver.recv_timeout(timeout.saturating_sub(start.elapsed())) {
+        Ok(buffer) => Ok(buffer?),
+        Err(RecvTimeoutError::Timeout) => Err(timed_out().into()),
+        Err(RecvTimeoutError::Disconnected) => {
+            let message = format!("failed to read the output of \"{command}\"");
+            Err(io::Error::other(message).into())
+        }
+    }
+}
+
+/// Converts the ASCII art to text, painting it with the colors of the
+/// `[custom_ascii]` table of the theme.
+pub fn ascii_to_text(buffer: &[u8], custom_ascii: &ASCII) -> Text<'static> {
+    match custom_ascii.get_color() {
+        Some(color) => strip_color_placeholders(buffer)
+            .into_text()
+            .unwrap_or_default()
+            .patch_style(Style::default().fg(color)),
+        None => fill_color_placeholders(buffer, custom_ascii.get_colors())
+            .into_text()
+            .unwrap_or_default(),
+    }
+}
+
+/// The height from which ASCII art is too tall to be displayed.
+pub const MAX_ASCII_HEIGHT: usize = 50;
+
+/// The width that images are limited to, which leaves room for the readouts.
+const MAX_IMAGE_WIDTH: u16 = 200;
+
+/// The characters of the ASCII charset, ordered from the dimmest to the
+/// brightest.
+const ASCII_RAMP: &[u8] = b" .:-=+*#%@";
+
+/// Pixels more transparent than this are left blank.
+const ALPHA_THRESHOLD: u8 = 128;
+
+pub fn get_ascii_from_image(file_path: &Path, custom_ascii: &ASCII) -> Result<Text<'static>> {
+    let image = image::open(file_path)?;
+    Ok(image_to_text(
+        &image,
+        custom_ascii.get_image_width(),
+        custom_ascii.get_image_height(),
+        custom_ascii.get_image_charset(),
+    ))
+}
+
+/// Converts an image to colored text that's `width` cells wide. The height
+/// is derived from the aspect ratio of the image, unless it's specified.
+pub fn image_to_text(
+    image: &DynamicImage,
+    width: u16,
+    height: Option<u16>,
+    charset: ImageCharset,
+) -> Text<'static> {
+    // the number of pixels that each cell is made of.
+    let (cell_width, cell_height) = match charset {
+        ImageCharset::Ascii => (1, 1),
+        ImageCharset::HalfBlocks => (1, 2),
+        ImageCharset::Braille => (2, 4),
+    };
+
+    // the ASCII art is discarded if it's any taller than this.
+    let max_height = MAX_ASCII_HEIGHT as u64 - 1;
+
+    let width = u64::from(width.clamp(1, MAX_IMAGE_WIDTH));
+    let (width, height) = match height {
+        Some(height) => (width, u64::from(height.max(1)).min(max_height)),
+        None => {
+            // cells are roughly twice as tall as they are wide.
+            let height =
+                (width * u64::from(image.height()) / u64::from(image.width().max(1)) / 2).max(1);
+
+            // the width is scaled down along with the height, so that the
+            // aspect ratio is preserved.
+            if height > max_height {
+                ((width * max_height / height).max(1), max_height)
+            } else {
+                (width, height)
+            }
+        }
+    };
+    // neither can exceed the width and height limits.
+    let (width, height) = (width as u32, height as u32);
+
+    let image = image
+        .resize_exact(
+            width * cell_width,
+            height * cell_height,
+            FilterType::Triangle,
+        )
+        .to_rgba8();
+
+    let lines: Vec<Line> = (0..height)
+        .map(|y| {
+            let spans: Vec<Span> = (0..width)
+                .map(|x| match charset {
+                    ImageCharset::Ascii => ascii_cell(&image, x, y),
+                    ImageCharset::HalfBlocks => half_block_cell(&image, x, y * 2),
+                    ImageCharset::Braille => braille_cell(&image, x * 2, y * 4),
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+
+    Text::from(lines)
+}
+
+fn is_visible(pixel: &Rgba<u8>) -> bool {
+    pixel[3] >= ALPHA_THRESHOLD
+}
+
+fn luminance(pixel: &Rgba<u8>) -> f32 {
+    (0.2126 * f32::from(pixel[0]) + 0.7152 * f32::from(pixel[1]) + 0.0722 * f32::from(pixel[2]))
+        / 255.0
+}
+
+fn pixel_color(pixel: &Rgba<u8>) -> Color {
+    Color::Rgb(pixel[0], pixel[1], pixel[2])
+}
+
+fn ascii_cell(image: &RgbaImage, x: u32, y: u32) -> Span<'static> {
+    let pixel = image.get_pixel(x, y);
+    if !is_visible(pixel) {
+        return Span::raw(" ");
+    }
+
+    let index = (luminance(pixel) * (ASCII_RAMP.len() - 1) as f32).round() as usize;
+    let symbol = char::from(ASCII_RAMP[index]).to_string();
+    Span::styled(symbol, Style::default().fg(pixel_color(pixel)))
+}
+
+fn half_block_cell(image: &RgbaImage, x: u32, y: u32) -> Span<'static> {
+    let top = image.get_pixel(x, y);
+    let bottom = image.get_pixel(x, y + 1);
+
+    match (is_visible(top), is_visible(bottom)) {
+        (true, true) => Span::styled(
+            "▀",
+            Style::default()
+                .fg(pixel_color(top))
+                .bg(pixel_color(bottom)),
+        ),
+        (true, false) => Span::styled("▀", Style::default().fg(pixel_color(top))),
+        (false, true) => Span::styled("▄", Style::default().fg(pixel_color(bottom))),
+        (false, false) => Span::raw(" "),
+    }
+}
+
+fn braille_cell(image: &RgbaImage, x: u32, y: u32) -> Span<'static> {
+    // the bit of each dot of a braille character, indexed by their position
+    // within the character.
+    const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+    let mut bits = 0;
+    let mut color = [0u32; 3];
+    let mut lit = 0;
+
+    for (dy, row) in DOTS.iter().enumerate() {
+        for (dx, bit) in row.iter().enumerate() {
+            let pixel = image.get_pixel(x + dx as u32, y + dy as u32);
+            if is_visible(pixel) {
+                bits |= bit;
+                lit += 1;
+                for (channel, value) in color.iter_mut().zip(pixel.0) {
+                    *channel += u32::from(value);
+                }
+            }
+        }
+    }
+
+    if lit == 0 {
+        return Span::raw(" ");
+    }
+
+    let symbol = char::from_u32(0x2800 + bits).unwrap_or(' ').to_string();
+    let [r, g, b] = color.map(|channel| (channel / lit) as u8);
+    Span::styled(symbol, Style::default().fg(Color::Rgb(r, g, b)))
 }
 
 // The following is a slightly modified
 // version of neofetch's Apple ASCII art.
 #[cfg(target_os = "macos")]
-pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
+pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<AsciiArt> {
     match size {
         AsciiSize::Big => {
             let art: Vec<Span> = vec![
@@ -104,11 +586,44 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Span::styled("       .MMMM,.    .MMMM,.", *BLUE),
             ];
 
-            vec![Text::from(
-                art.iter()
-                    .map(|f| Line::from(f.to_owned()))
-                    .collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter()
+                        .map(|f| Line::from(f.to_owned()))
+                        .collect::<Vec<Line>>(),
+                    "taken from Dylan Araps' Neofetch",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"              ,."#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"             ,'/"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"        .-~~'/ ~~-."#, *YELLOW)]),
+                        Line::from(vec![Span::styled(r#"      .'          `."#, *YELLOW)]),
+                        Line::from(vec![Span::styled(r#"     /             /"#, *RED)]),
+                        Line::from(vec![Span::styled(r#"    |             ("#, *RED)]),
+                        Line::from(vec![Span::styled(r#"    |              \"#, *MAGENTA)]),
+                        Line::from(vec![Span::styled(r#"     \              `."#, *MAGENTA)]),
+                        Line::from(vec![Span::styled(r#"      `.    .--.    .'"#, *BLUE)]),
+                        Line::from(vec![Span::styled(r#"        `--'    `--'"#, *BLUE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"          ##"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"         ##"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"   #####    #####"#, *YELLOW)]),
+                        Line::from(vec![Span::styled(r#" ##################"#, *YELLOW)]),
+                        Line::from(vec![Span::styled(r#"##################"#, *RED)]),
+                        Line::from(vec![Span::styled(r#"##################"#, *RED)]),
+                        Line::from(vec![Span::styled(r#" ###################"#, *MAGENTA)]),
+                        Line::from(vec![Span::styled(r#"  #################"#, *MAGENTA)]),
+                        Line::from(vec![Span::styled(r#"   ###############"#, *BLUE)]),
+                        Line::from(vec![Span::styled(r#"    ####    ####"#, *BLUE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
         AsciiSize::Small => {
             // The following Apple ASCII art was made by Joan Stark (jgs)
@@ -122,17 +637,42 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Span::styled("  `.__.-.__.'", *MAGENTA),
             ];
 
-            vec![Text::from(
-                art.iter()
-                    .map(|f| Line::from(f.to_owned()))
-                    .collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter()
+                        .map(|f| Line::from(f.to_owned()))
+                        .collect::<Vec<Line>>(),
+                    "made by Joan Stark",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"    ,"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" .-'`-."#, *YELLOW)]),
+                        Line::from(vec![Span::styled(r#"/    .'"#, *RED)]),
+                        Line::from(vec![Span::styled(r#"|   ("#, *RED)]),
+                        Line::from(vec![Span::styled(r#"\    `."#, *MAGENTA)]),
+                        Line::from(vec![Span::styled(r#" `-^-'"#, *BLUE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"    #"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" ### ###"#, *YELLOW)]),
+                        Line::from(vec![Span::styled(r#"#######"#, *RED)]),
+                        Line::from(vec![Span::styled(r#"#######"#, *RED)]),
+                        Line::from(vec![Span::styled(r#" #######"#, *MAGENTA)]),
+                        Line::from(vec![Span::styled(r#"  ## ##"#, *BLUE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
     }
 }
 
 #[cfg(target_os = "android")]
-pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
+pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<AsciiArt> {
     match size {
         AsciiSize::Big => {
             let art: Vec<Span> = vec![
@@ -151,11 +691,43 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Span::styled("     ooo   ooo", *GREEN),
             ];
 
-            vec![Text::from(
-                art.iter()
-                    .map(|f| Line::from(f.to_owned()))
-                    .collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter()
+                        .map(|f| Line::from(f.to_owned()))
+                        .collect::<Vec<Line>>(),
+                    "artist unknown",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"   \          /"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"    \.------./"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"   .'        '."#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"  /   O    O   \"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" |              |"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" '--------------'"#, *GREEN)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"   #          #"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"    #  ####  #"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"     ########"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"   ############"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"   ###  ##  ###"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"   ############"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" "#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"##  ############  ##"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"##  ############  ##"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"##  ############  ##"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"    ############"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"      ###  ###"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#"      ###  ###"#, *GREEN)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
         AsciiSize::Small => {
             let art: Vec<Span> = vec![
@@ -167,17 +739,38 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Span::styled(" oooooooooooo  ", *GREEN),
             ];
 
-            vec![Text::from(
-                art.iter()
-                    .map(|f| Line::from(f.to_owned()))
-                    .collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter()
+                        .map(|f| Line::from(f.to_owned()))
+                        .collect::<Vec<Line>>(),
+                    "artist unknown",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#" \    /"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" .-''-."#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" | oo |"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" '----'"#, *GREEN)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#" #    #"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" ######"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" # ## #"#, *GREEN)]),
+                        Line::from(vec![Span::styled(r#" ######"#, *GREEN)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
     }
 }
 
 #[cfg(target_os = "windows")]
-pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
+pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<AsciiArt> {
     match size {
         AsciiSize::Big => {
             let art: Vec<Span> = vec![
@@ -199,11 +792,121 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Span::styled(r#"WWWWWWWWWWWWWW  WWWWWWWWWWWWWW"#, *BLUE),
             ];
 
-            vec![Text::from(
-                art.iter()
-                    .map(|f| Line::from(f.to_owned()))
-                    .collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter()
+                        .map(|f| Line::from(f.to_owned()))
+                        .collect::<Vec<Line>>(),
+                    "artist unknown",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *RED),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *RED),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *RED),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *RED),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *RED),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *RED),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *GREEN),
+                        ]),
+                        Line::from(vec![Span::styled(r#" "#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *BLUE),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *BLUE),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *BLUE),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *BLUE),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *BLUE),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"############"#, *BLUE),
+                            Span::styled(r#"  "#, *WHITE),
+                            Span::styled(r#"############"#, *YELLOW),
+                        ]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(
+                            r#" ______________ ______________"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|              |              |"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|              |              |"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|              |              |"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|______________|______________|"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|              |              |"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|              |              |"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|              |              |"#,
+                            *BLUE,
+                        )]),
+                        Line::from(vec![Span::styled(
+                            r#"|______________|______________|"#,
+                            *BLUE,
+                        )]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
         AsciiSize::Small => {
             let art: Vec<Span> = vec![
@@ -216,18 +919,67 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Span::styled("wwww  wwww", *BLUE),
             ];
 
-            vec![Text::from(
-                art.iter()
-                    .map(|f| Line::from(f.to_owned()))
-                    .collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter()
+                        .map(|f| Line::from(f.to_owned()))
+                        .collect::<Vec<Line>>(),
+                    "artist unknown",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![
+                            Span::styled(r#"####"#, *RED),
+                            Span::styled(r#" "#, *WHITE),
+                            Span::styled(r#"####"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"####"#, *RED),
+                            Span::styled(r#" "#, *WHITE),
+                            Span::styled(r#"####"#, *GREEN),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"####"#, *RED),
+                            Span::styled(r#" "#, *WHITE),
+                            Span::styled(r#"####"#, *GREEN),
+                        ]),
+                        Line::from(vec![Span::styled(r#" "#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#"####"#, *BLUE),
+                            Span::styled(r#" "#, *WHITE),
+                            Span::styled(r#"####"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"####"#, *BLUE),
+                            Span::styled(r#" "#, *WHITE),
+                            Span::styled(r#"####"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"####"#, *BLUE),
+                            Span::styled(r#" "#, *WHITE),
+                            Span::styled(r#"####"#, *YELLOW),
+                        ]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#" ____ ____"#, *BLUE)]),
+                        Line::from(vec![Span::styled(r#"|    |    |"#, *BLUE)]),
+                        Line::from(vec![Span::styled(r#"|____|____|"#, *BLUE)]),
+                        Line::from(vec![Span::styled(r#"|    |    |"#, *BLUE)]),
+                        Line::from(vec![Span::styled(r#"|____|____|"#, *BLUE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
     }
 }
 
 // The following penguin ASCII art was made by Joan Stark (jgs)
 #[cfg(target_os = "linux")]
-pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
+pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<AsciiArt> {
     match size {
         AsciiSize::Big => {
             let art: Vec<Line> = vec![
@@ -271,9 +1023,48 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Line::from(vec![Span::styled("`--..__)     `._.'", *YELLOW)]),
             ];
 
-            vec![Text::from(
-                art.iter().map(|f| f.to_owned()).collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter().map(|f| f.to_owned()).collect::<Vec<Line>>(),
+                    "made by Joan Stark",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"       _.---._"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"     .'       `."#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"    /   o   o   \"#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#"   |      "#, *WHITE),
+                            Span::styled(r#"V"#, *YELLOW),
+                            Span::styled(r#"      |"#, *WHITE),
+                        ]),
+                        Line::from(vec![Span::styled(r#"   |   .-----.   |"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"    \ (       ) /"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"   .-`.       .'-."#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"  (    `-----'    )"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"   `-.._______..-'"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"      /__/ \__\"#, *YELLOW)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"        .--."#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"       ( oo )"#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#"       / "#, *WHITE),
+                            Span::styled(r#"vv"#, *YELLOW),
+                            Span::styled(r#" \"#, *WHITE),
+                        ]),
+                        Line::from(vec![Span::styled(r#"      /|    |\"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"     / |    | \"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"    (  |    |  )"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"     `-'----'-'"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"       _/  \_"#, *YELLOW)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
         AsciiSize::Small => {
             // The following penguin ASCII art was found and
@@ -298,13 +1089,47 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Line::from(vec![Span::styled("\\___)=(___/", *WHITE)]),
             ];
 
-            vec![Text::from(art)]
+            vec![
+                AsciiArt::new(
+                    Text::from(art),
+                    "taken from Christopher Johnson's ASCII art collection",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"   .-."#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"  (o o)"#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#"  | "#, *WHITE),
+                            Span::styled(r#"V"#, *YELLOW),
+                            Span::styled(r#" |"#, *WHITE),
+                        ]),
+                        Line::from(vec![Span::styled(r#" /|   |\"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"(_|   |_)"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"  ^^ ^^"#, *YELLOW)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"  .--."#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#" ( oo )"#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#" /`"#, *WHITE),
+                            Span::styled(r#"vv"#, *YELLOW),
+                            Span::styled(r#"'\"#, *WHITE),
+                        ]),
+                        Line::from(vec![Span::styled(r#" \    /"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"  `""`"#, *YELLOW)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
     }
 }
 
 #[cfg(target_os = "freebsd")]
-pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
+pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<AsciiArt> {
     // The following ASCII art was made by Dylan Araps
     // and taken from https://github.com/dylanaraps/pfetch
 
@@ -317,11 +1142,65 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
         Line::from(vec![Span::styled("   '-_____-'", *RED)]),
     ];
 
-    vec![Text::from(art)]
+    let mut variants = vec![AsciiArt::new(
+        Text::from(art),
+        "taken from Dylan Araps' pfetch",
+    )];
+
+    match size {
+        AsciiSize::Big => variants.extend([
+            AsciiArt::new(
+                Text::from(vec![
+                    Line::from(vec![Span::styled(r#"/\,-'''''''''-,/\"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"\_)           (_//"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" |               |"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" |               |"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" |               |"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"  ;             ;"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"   '-_________-'"#, *RED)]),
+                ]),
+                ORIGINAL,
+            ),
+            AsciiArt::new(
+                Text::from(vec![
+                    Line::from(vec![Span::styled(r#" /\               /\"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" \ \  .-------.  / /"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"  \ \/         \/ /"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"   |             |"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"   |             |"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"    \           /"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"     `-._____.-'"#, *RED)]),
+                ]),
+                ORIGINAL,
+            ),
+        ]),
+        AsciiSize::Small => variants.extend([
+            AsciiArt::new(
+                Text::from(vec![
+                    Line::from(vec![Span::styled(r#"/\.---./\"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"\_)   (_/"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" (     )"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"  `---'"#, *RED)]),
+                ]),
+                ORIGINAL,
+            ),
+            AsciiArt::new(
+                Text::from(vec![
+                    Line::from(vec![Span::styled(r#"/\ .-. /\"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" \/   \/"#, *RED)]),
+                    Line::from(vec![Span::styled(r#" (     )"#, *RED)]),
+                    Line::from(vec![Span::styled(r#"  `-.-'"#, *RED)]),
+                ]),
+                ORIGINAL,
+            ),
+        ]),
+    }
+
+    variants
 }
 
 #[cfg(target_os = "netbsd")]
-pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
+pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<AsciiArt> {
     match size {
         AsciiSize::Big => {
             let art: Vec<Line> = vec![
@@ -350,9 +1229,57 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Line::from(vec![Span::styled("          \\\\", *WHITE)]),
             ];
 
-            vec![Text::from(
-                art.iter().map(|f| f.to_owned()).collect::<Vec<Line>>(),
-            )]
+            vec![
+                AsciiArt::new(
+                    art.iter().map(|f| f.to_owned()).collect::<Vec<Line>>(),
+                    "artist unknown",
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![
+                            Span::styled(r#"()"#, *WHITE),
+                            Span::styled(r#"~~~~~~~~~~~~~~"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#" ||"#, *WHITE),
+                            Span::styled(r#"~~~~~~~~~~~~"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#" ||"#, *WHITE),
+                            Span::styled(r#"~~~~~~~~~~~~~~"#, *YELLOW),
+                        ]),
+                        Line::from(vec![Span::styled(r#" ||"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#" ||"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#" ||"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#" ||"#, *WHITE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![Span::styled(r#"()"#, *WHITE)]),
+                        Line::from(vec![
+                            Span::styled(r#"||"#, *WHITE),
+                            Span::styled(r#"\~~~~.      .~~~~."#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"||"#, *WHITE),
+                            Span::styled(r#" `~~~~~~~~~~'    |"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"||"#, *WHITE),
+                            Span::styled(r#"  .~~~~.      .~~'"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"||"#, *WHITE),
+                            Span::styled(r#" '      `~~~~'"#, *YELLOW),
+                        ]),
+                        Line::from(vec![Span::styled(r#"||"#, *WHITE)]),
+                        Line::from(vec![Span::styled(r#"||"#, *WHITE)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
         }
         AsciiSize::Small => {
             let art: Vec<Line> = vec![
@@ -373,7 +1300,146 @@ pub(crate) fn get_ascii_art(size: AsciiSize) -> Vec<Text<'static>> {
                 Line::from(vec![Span::styled("     \\\\", *BLACK)]),
             ];
 
-            vec![Text::from(art)]
+            vec![
+                AsciiArt::new(Text::from(art), "artist unknown"),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![
+                            Span::styled(r#"()"#, *BLACK),
+                            Span::styled(r#"~~~~~~~~"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#" ||"#, *BLACK),
+                            Span::styled(r#"~~~~~~"#, *YELLOW),
+                        ]),
+                        Line::from(vec![Span::styled(r#" ||"#, *BLACK)]),
+                        Line::from(vec![Span::styled(r#" ||"#, *BLACK)]),
+                    ]),
+                    ORIGINAL,
+                ),
+                AsciiArt::new(
+                    Text::from(vec![
+                        Line::from(vec![
+                            Span::styled(r#"()"#, *BLACK),
+                            Span::styled(r#"_.~~._"#, *YELLOW),
+                        ]),
+                        Line::from(vec![
+                            Span::styled(r#"||"#, *BLACK),
+                            Span::styled(r#" `~~~'"#, *YELLOW),
+                        ]),
+                        Line::from(vec![Span::styled(r#"||"#, *BLACK)]),
+                        Line::from(vec![Span::styled(r#"||"#, *BLACK)]),
+                    ]),
+                    ORIGINAL,
+                ),
+            ]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_variants() {
+        let big = get_ascii_art(AsciiSize::Big);
+        let small = get_ascii_art(AsciiSize::Small);
+
+        // an index selects a variant of either size.
+        assert_eq!(big.len(), small.len());
+        assert!(big.len() >= 3);
+    }
+
+    #[test]
+    fn test_check_selection() {
+        let count = get_ascii_art(AsciiSize::Big).len();
+        let options = |select| AsciiOptions { select, seed: None };
+
+        assert!(check_selection(&options(AsciiSelect::First)).is_ok());
+        assert!(check_selection(&options(AsciiSelect::Index(count - 1))).is_ok());
+
+        let err = check_selection(&options(AsciiSelect::Index(count))).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("from 0 to {}", count - 1)));
+    }
+
+    #[test]
+    fn test_fill_color_placeholders() {
+        let ascii = "${c1}/\\${c2}__${c7}/\\\n${c1} \\/";
+        let buffer = fill_color_placeholders(ascii.as_bytes(), &[Color::Blue, Color::Red]);
+        let text = buffer.into_text().unwrap();
+
+        assert_eq!(text.width(), 6);
+        assert_eq!(text.lines[0].spans[0].style.fg, Some(Color::Blue));
+        assert_eq!(text.lines[0].spans[1].style.fg, Some(Color::Red));
+        assert_eq!(text.lines[0].spans[2].style.fg, Some(Color::Reset));
+        assert_eq!(text.lines[1].to_string(), " \\/");
+
+        let buffer = fill_color_placeholders(b"${c1}#", &[Color::Rgb(255, 0, 136)]);
+        let text = buffer.into_text().unwrap();
+        assert_eq!(
+            text.lines[0].spans[0].style.fg,
+            Some(Color::Rgb(255, 0, 136))
+        );
+    }
+
+    #[test]
+    fn test_ascii_to_text_color_override() {
+        let custom_ascii: ASCII = toml::from_str("color = \"Green\"").unwrap();
+        let text = ascii_to_text(b"${c1}/\\${c2}__\n${c3} \\/", &custom_ascii);
+
+        assert_eq!(text.lines[0].to_string(), "/\\__");
+        assert_eq!(text.lines[1].to_string(), " \\/");
+        for span in text.lines.iter().flat_map(|line| line.spans.iter()) {
+            assert_eq!(text.style.patch(span.style).fg, Some(Color::Green));
         }
     }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_read_ascii_command_timeout() {
+        let timeout = Duration::from_millis(200);
+        assert_eq!(read_ascii_command("echo hi", timeout).unwrap(), b"hi\n");
+
+        // the backgrounded sleep keeps stdout open after the shell has exited.
+        let start = Instant::now();
+        assert!(read_ascii_command("echo hi; sleep 5 &", timeout).is_err());
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_image_to_text() {
+        let red = Rgba([255, 0, 0, 255]);
+        let blue = Rgba([0, 0, 255, 255]);
+        let clear = Rgba([0, 0, 0, 0]);
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_fn(4, 4, |_, y| match y {
+            0 => red,
+            1 => blue,
+            _ => clear,
+        }));
+
+        let text = image_to_text(&image, 4, Some(2), ImageCharset::HalfBlocks);
+        assert_eq!(text.width(), 4);
+        assert_eq!(text.height(), 2);
+        assert_eq!(text.lines[0].spans[0].content, "▀");
+        assert_eq!(text.lines[0].spans[0].style.fg, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(text.lines[0].spans[0].style.bg, Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(text.lines[1].to_string(), "    ");
+
+        let text = image_to_text(&image, 2, Some(1), ImageCharset::Braille);
+        assert_eq!(text.lines[0].to_string(), "⠛⠛");
+
+        let tall = DynamicImage::ImageRgba8(RgbaImage::from_pixel(1, 10_000, red));
+        let text = image_to_text(&tall, u16::MAX, None, ImageCharset::Ascii);
+        assert_eq!(text.height(), MAX_ASCII_HEIGHT - 1);
+        assert_eq!(text.width(), 1);
+
+        let text = image_to_text(&image, 4, Some(u16::MAX), ImageCharset::Ascii);
+        assert_eq!(text.height(), MAX_ASCII_HEIGHT - 1);
+
+        let text = image_to_text(&image, u16::MAX, Some(1), ImageCharset::Ascii);
+        assert_eq!(text.width(), usize::from(MAX_IMAGE_WIDTH));
+    }
 }
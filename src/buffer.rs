@@ -1,10 +1,12 @@
-use crate::data::Readout;
+use crate::cli::Opt;
+use crate::data;
+use crate::theme::components::SectionLayout;
 use crate::theme::Theme;
-use crate::widgets::readout::ReadoutList;
+use crate::widgets::readout::{ReadoutList, ReadoutSection};
 use atty::Stream;
 use ratatui::backend::{Backend, CrosstermBackend};
 use ratatui::buffer::{Buffer, Cell};
-use ratatui::layout::{Margin, Position, Rect};
+use ratatui::layout::{Margin, Position, Rect, Size};
 use ratatui::text::Text;
 use ratatui::widgets::{Block, Borders, Paragraph, Widget};
 use std::io;
@@ -15,6 +17,40 @@ pub fn create_backend() -> CrosstermBackend<Stdout> {
     CrosstermBackend::new(io::stdout())
 }
 
+/// Returns the size of the terminal, unless the output is redirected.
+pub fn get_terminal_size(backend: &CrosstermBackend<Stdout>) -> Option<Size> {
+    if !atty::is(Stream::Stdout) {
+        return None;
+    }
+
+    backend.size().ok()
+}
+
+/// Returns the width and height taken up by the readouts when they are drawn
+/// on their own.
+pub fn measure_readout_data(sections: Vec<ReadoutSection>, theme: Theme, opt: &Opt) -> Size {
+    let area = Rect::new(0, 0, 500, 50);
+    let mut buf = Buffer::empty(area);
+    draw_readout_data(sections, theme, opt, &mut buf, area);
+
+    match find_last_buffer_cell_index(&buf) {
+        Some((_, last_y)) => Size::new(find_widest_cell(&buf, last_y + 1), last_y + 1),
+        None => Size::default(),
+    }
+}
+
+/// Whether the ASCII art fits in the terminal along with the readouts, either
+/// next to or above them.
+pub fn ascii_fits(ascii: &Text, readout_size: Size, term_size: Size, stacked: bool) -> bool {
+    let (width, height) = (ascii.width() as u16, ascii.height() as u16);
+
+    if stacked {
+        width < term_size.width && height + readout_size.height + 2 <= term_size.height
+    } else {
+        width + readout_size.width + 4 <= term_size.width && height < term_size.height
+    }
+}
+
 pub fn find_widest_cell(buf: &Buffer, last_y: u16) -> u16 {
     let area = &buf.area;
     let mut widest: u16 = 0;
@@ -40,8 +76,7 @@ pub fn find_last_buffer_cell_index(buf: &Buffer) -> Option<(u16, u16)> {
         .content
         .iter()
         .enumerate()
-        .filter(|p| !(*(p.1)).eq(&empty_cell))
-        .last()
+        .rfind(|p| !(*(p.1)).eq(&empty_cell))
     {
         return Some(buf.pos_of(idx));
     }
@@ -61,21 +96,53 @@ pub fn draw_ascii(ascii: Text<'static>, tmp_buffer: &mut Buffer) -> Rect {
     ascii_rect
 }
 
-pub fn draw_readout_data(data: Vec<Readout>, theme: Theme, buf: &mut Buffer, area: Rect) {
-    let mut list = ReadoutList::new(data, &theme);
+pub fn draw_readout_data(
+    sections: Vec<ReadoutSection>,
+    theme: Theme,
+    opt: &Opt,
+    buf: &mut Buffer,
+    area: Rect,
+) {
+    // sections are rendered in their own box, titled after them, when there's
+    // more than one of them.
+    let boxed_sections =
+        sections.len() > 1 && matches!(theme.get_sections().get_layout(), SectionLayout::Box);
+    let mut list = ReadoutList::new(vec![], &theme).sections(sections);
+    let block = theme.get_block();
+    let header = theme.get_header();
+
+    if let Some(text) = header.get_text() {
+        let text = data::fill_placeholders(text, opt);
+        list = list.header(header.to_text(text, theme.get_key_color()));
+    }
+
+    if let Some(footer) = header.get_footer() {
+        list = list.footer(header.footer_to_text(data::fill_placeholders(footer, opt)));
+    }
+
+    if block.is_visible() || boxed_sections {
+        let mut widget = Block::default()
+            .border_type(block.get_border_type())
+            .border_style(block.get_border_style())
+            .title_style(block.get_title_style())
+            .title_alignment(block.get_title_alignment())
+            .title_position(block.get_title_position())
+            .borders(Borders::ALL);
+
+        if !boxed_sections {
+            widget = widget.title(data::fill_placeholders(&block.get_title(), opt));
+        }
+
+        if let Some(set) = block.get_border_set() {
+            widget = widget.border_set(set);
+        }
 
-    if theme.get_block().is_visible() {
         list = list
             .block_inner_margin(Margin {
-                horizontal: theme.get_block().get_horizontal_margin(),
-                vertical: theme.get_block().get_vertical_margin(),
+                horizontal: block.get_horizontal_margin(),
+                vertical: block.get_vertical_margin(),
             })
-            .block(
-                Block::default()
-                    .border_type(theme.get_block().get_border_type())
-                    .title(theme.get_block().get_title())
-                    .borders(Borders::ALL),
-            );
+            .block(widget);
     }
 
     list.render(area, buf);
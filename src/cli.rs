@@ -1,6 +1,8 @@
+use crate::ascii;
 use crate::config;
 use crate::data;
 use crate::error;
+use crate::replace;
 use clap::Parser;
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -25,10 +27,30 @@ pub struct Opt {
         help = "Displays only the specified readouts",
         hide_possible_values = true,
         use_value_delimiter = true,
-        value_delimiter = ','
+        value_delimiter = ',',
+        value_parser = |s: &str| s.parse::<data::ReadoutKey>()
     )]
     pub show: Option<Vec<data::ReadoutKey>>,
 
+    #[clap(
+        long = "hide",
+        help = "Hides the specified readouts",
+        hide_possible_values = true,
+        use_value_delimiter = true,
+        value_delimiter = ',',
+        value_parser = |s: &str| s.parse::<data::ReadoutKey>()
+    )]
+    pub hide: Option<Vec<data::ReadoutKey>>,
+
+    #[clap(skip)]
+    pub section: Option<Vec<Section>>,
+
+    #[clap(skip)]
+    pub replace: Option<Vec<replace::ReplaceRule>>,
+
+    #[clap(skip)]
+    pub tidy: bool,
+
     #[clap(short = 'd', long = "doctor", help = "Checks the system for failures")]
     #[serde(skip_serializing, skip_deserializing)]
     pub doctor: bool,
@@ -49,6 +71,49 @@ pub struct Opt {
     )]
     pub memory_percentage: bool,
 
+    #[clap(long = "swap-percentage", help = "Show swap usage in percentage")]
+    pub swap_percentage: bool,
+
+    #[clap(
+        long = "temperature-unit",
+        value_enum,
+        help = "Specify the unit of the Temperature readout"
+    )]
+    pub temperature_unit: Option<data::temperature::TemperatureUnit>,
+
+    #[clap(skip)]
+    pub temperature_sensors: Option<Vec<String>>,
+
+    #[clap(skip)]
+    pub network_interfaces: Option<Vec<String>>,
+
+    #[clap(skip)]
+    pub network_mac: bool,
+
+    #[clap(skip)]
+    pub network_speed: bool,
+
+    #[clap(
+        long = "throughput-interval",
+        help = "Specify the sampling interval of the Throughput readout, in milliseconds"
+    )]
+    pub throughput_interval: Option<u64>,
+
+    #[clap(skip)]
+    pub throughput_totals: bool,
+
+    #[clap(skip)]
+    pub datetime_format: Option<String>,
+
+    #[clap(skip)]
+    pub init_services: bool,
+
+    #[clap(
+        long = "normalize-load-average",
+        help = "Divide the load averages by the number of CPU cores"
+    )]
+    pub normalize_load_average: bool,
+
     #[clap(
         short = 'p',
         long = "disk-space-percentage",
@@ -83,6 +148,12 @@ pub struct Opt {
     #[clap(short = 't', long = "theme", help = "Specify the name of the theme")]
     pub theme: Option<String>,
 
+    #[clap(
+        long = "theme-from-palette",
+        help = "Generate a theme from a pywal colors.json file or a base16 scheme"
+    )]
+    pub theme_from_palette: Option<std::path::PathBuf>,
+
     #[clap(
         long = "list-themes",
         short = 'l',
@@ -106,12 +177,85 @@ pub struct Opt {
     #[serde(skip_serializing, skip_deserializing)]
     pub ascii_artists: bool,
 
+    #[clap(
+        long = "ascii",
+        help = "Specify the path to a file containing ASCII art, or - to read it from stdin"
+    )]
+    #[serde(skip_serializing, skip_deserializing)]
+    pub ascii_path: Option<std::path::PathBuf>,
+
+    #[clap(
+        long = "list-ascii",
+        help = "Lists the built-in ASCII art variants along with their index and artist"
+    )]
+    #[serde(skip_serializing, skip_deserializing)]
+    pub list_ascii: bool,
+
+    #[clap(
+        long = "ascii-index",
+        help = "Specify the index of the built-in ASCII art to display, see --list-ascii"
+    )]
+    #[serde(skip_serializing, skip_deserializing)]
+    pub ascii_index: Option<usize>,
+
+    #[clap(
+        long = "ascii-seed",
+        help = "Specify the seed used to randomly select the built-in ASCII art"
+    )]
+    #[serde(skip_serializing, skip_deserializing)]
+    pub ascii_seed: Option<u64>,
+
+    #[clap(skip)]
+    pub ascii: ascii::AsciiOptions,
+
+    #[clap(
+        long = "redact",
+        help = "Masks sensitive information, e.g. usernames, hostnames and IP addresses"
+    )]
+    pub redact: bool,
+
+    #[clap(skip)]
+    pub redact_readouts: Option<Vec<data::ReadoutKey>>,
+
+    #[clap(skip)]
+    pub redact_keep_edges: bool,
+
     #[clap(
         long = "interface",
         short = 'i',
-        help = "Specify the network interface for the LocalIP readout"
+        use_value_delimiter = true,
+        value_delimiter = ',',
+        help = "Specify the network interface(s) for the LocalIP readout, in order of preference"
     )]
-    pub interface: Option<String>,
+    #[serde(deserialize_with = "string_or_list")]
+    pub interface: Option<Vec<String>>,
+}
+
+/// Accepts either a single string or a list of them, e.g. `interface = "wlan0"`
+/// or `interface = ["wlan0", "eth0"]`.
+fn string_or_list<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrList {
+        String(String),
+        List(Vec<String>),
+    }
+
+    Ok(match StringOrList::deserialize(deserializer)? {
+        StringOrList::String(s) => Some(vec![s]),
+        StringOrList::List(l) => Some(l),
+    })
+}
+
+/// A titled group of readouts, as defined by a `[[section]]` table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Section {
+    pub title: Option<String>,
+    pub show: Vec<data::ReadoutKey>,
 }
 
 impl Opt {
@@ -148,6 +292,22 @@ impl Opt {
             self.memory_percentage = args.memory_percentage;
         }
 
+        if args.swap_percentage {
+            self.swap_percentage = args.swap_percentage;
+        }
+
+        if args.temperature_unit.is_some() {
+            self.temperature_unit = args.temperature_unit;
+        }
+
+        if args.throughput_interval.is_some() {
+            self.throughput_interval = args.throughput_interval;
+        }
+
+        if args.normalize_load_average {
+            self.normalize_load_average = true;
+        }
+
         if args.disk_space_percentage {
             self.disk_space_percentage = args.disk_space_percentage;
         }
@@ -160,6 +320,30 @@ impl Opt {
             self.ascii_artists = true;
         }
 
+        if args.ascii_path.is_some() {
+            self.ascii_path = args.ascii_path;
+        }
+
+        if args.list_ascii {
+            self.list_ascii = true;
+        }
+
+        if let Some(index) = args.ascii_index {
+            self.ascii.select = ascii::AsciiSelect::Index(index);
+        }
+
+        if args.ascii_seed.is_some() {
+            // a seed only makes sense when the ASCII art is randomly selected.
+            if args.ascii_index.is_none() {
+                self.ascii.select = ascii::AsciiSelect::Random;
+            }
+            self.ascii.seed = args.ascii_seed;
+        }
+
+        if args.redact {
+            self.redact = true;
+        }
+
         if args.config.is_some() {
             self.config = args.config;
         }
@@ -168,10 +352,21 @@ impl Opt {
             self.theme = args.theme;
         }
 
+        if args.theme_from_palette.is_some() {
+            self.theme_from_palette = args.theme_from_palette;
+        }
+
         if args.show.is_some() {
+            // readouts passed on the command-line take precedence over the
+            // sections of the configuration file.
+            self.section = None;
             self.show = args.show;
         }
 
+        if args.hide.is_some() {
+            self.hide = args.hide;
+        }
+
         if args.interface.is_some() {
             self.interface = args.interface;
         }
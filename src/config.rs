@@ -46,7 +46,7 @@ mod tests {
         assert!(!opt.long_kernel);
         assert!(opt.current_shell);
         assert!(opt.physical_cores);
-        assert_eq!(opt.interface, Some(String::from("wlan0")));
+        assert_eq!(opt.interface, Some(vec![String::from("wlan0")]));
         Ok(())
     }
 }
@@ -0,0 +1,185 @@
+use libmacchina::traits::ReadoutError;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitSystem {
+    Systemd,
+    OpenRC,
+    Runit,
+    S6,
+    Dinit,
+    Shepherd,
+    BusyBox,
+    SysVinit,
+}
+
+impl fmt::Display for InitSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InitSystem::Systemd => write!(f, "systemd"),
+            InitSystem::OpenRC => write!(f, "OpenRC"),
+            InitSystem::Runit => write!(f, "runit"),
+            InitSystem::S6 => write!(f, "s6"),
+            InitSystem::Dinit => write!(f, "dinit"),
+            InitSystem::Shepherd => write!(f, "GNU Shepherd"),
+            InitSystem::BusyBox => write!(f, "BusyBox"),
+            InitSystem::SysVinit => write!(f, "SysVinit"),
+        }
+    }
+}
+
+impl InitSystem {
+    /// Recognizes an init system by the name of its executable.
+    fn from_name(name: &str) -> Option<InitSystem> {
+        match name {
+            "systemd" => Some(InitSystem::Systemd),
+            "openrc-init" | "openrc" => Some(InitSystem::OpenRC),
+            "runit" | "runit-init" => Some(InitSystem::Runit),
+            "s6-svscan" | "s6-linux-init" => Some(InitSystem::S6),
+            "dinit" => Some(InitSystem::Dinit),
+            "shepherd" => Some(InitSystem::Shepherd),
+            "busybox" => Some(InitSystem::BusyBox),
+            _ => None,
+        }
+    }
+}
+
+/// The number of services an init system reports as running or failed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Services {
+    pub running: Option<usize>,
+    pub failed: Option<usize>,
+}
+
+/// Returns the entries of the given directory, leaving out hidden ones.
+fn entries(dir: &Path) -> Option<Vec<std::path::PathBuf>> {
+    let entries = fs::read_dir(dir).ok()?;
+
+    Some(
+        entries
+            .flatten()
+            .filter(|e| !e.file_name().to_string_lossy().starts_with('.'))
+            .map(|e| e.path())
+            .collect(),
+    )
+}
+
+/// Detects the init system through the name of PID 1 and the target of its
+/// executable, found under the given root, which is `/` outside of tests.
+/// A generic `init` is told apart by the state directories it leaves behind.
+pub fn init_system(root: &Path) -> Result<InitSystem, ReadoutError> {
+    let comm = fs::read_to_string(root.join("proc/1/comm"))
+        .map(|comm| comm.trim().to_owned())
+        .map_err(|e| ReadoutError::Other(format!("Failed to read /proc/1/comm: {e}")))?;
+
+    // Reading the target of /proc/1/exe usually requires elevated privileges.
+    let exe = fs::read_link(root.join("proc/1/exe"))
+        .ok()
+        .and_then(|exe| Some(exe.file_name()?.to_string_lossy().into_owned()));
+
+    if let Some(init) =
+        InitSystem::from_name(&comm).or_else(|| InitSystem::from_name(exe.as_deref()?))
+    {
+        return Ok(init);
+    }
+
+    if comm != "init" && exe.as_deref() != Some("init") {
+        return Err(ReadoutError::Other(format!(
+            "The init system \"{comm}\" is not recognized."
+        )));
+    }
+
+    if root.join("run/openrc").is_dir() {
+        Ok(InitSystem::OpenRC)
+    } else if root.join("run/runit").is_dir() {
+        Ok(InitSystem::Runit)
+    } else {
+        Ok(InitSystem::SysVinit)
+    }
+}
+
+/// Counts the services of the given init system from the state it keeps under
+/// the given root, which is `/` outside of tests. Only OpenRC and runit keep
+/// such state in plain files.
+pub fn services(init: InitSystem, root: &Path) -> Option<Services> {
+    match init {
+        InitSystem::OpenRC => {
+            let running = entries(&root.join("run/openrc/started"))?.len();
+            let failed = entries(&root.join("run/openrc/failed")).map_or(0, |f| f.len());
+
+            Some(Services {
+                running: Some(running),
+                failed: Some(failed),
+            })
+        }
+        InitSystem::Runit => {
+            const SERVICE_DIRS: &[&str] = &["run/runit/service", "var/service", "etc/service"];
+
+            let services = SERVICE_DIRS
+                .iter()
+                .find_map(|dir| entries(&root.join(dir)))?;
+
+            let running = services
+                .iter()
+                .filter(|s| {
+                    fs::read_to_string(s.join("supervise/stat"))
+                        .is_ok_and(|stat| stat.trim() == "run")
+                })
+                .count();
+
+            Some(Services {
+                running: Some(running),
+                failed: None,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_system() {
+        let root = std::env::temp_dir().join(format!("macchina-init-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+
+        assert!(init_system(&root).is_err());
+
+        write("proc/1/comm", "systemd\n");
+        assert_eq!(init_system(&root).ok(), Some(InitSystem::Systemd));
+        assert_eq!(services(InitSystem::Systemd, &root), None);
+
+        write("proc/1/comm", "init\n");
+        assert_eq!(init_system(&root).ok(), Some(InitSystem::SysVinit));
+
+        write("run/openrc/softlevel", "default\n");
+        write("run/openrc/started/sshd", "");
+        write("run/openrc/started/udev", "");
+        write("run/openrc/failed/ntpd", "");
+        assert_eq!(init_system(&root).ok(), Some(InitSystem::OpenRC));
+        assert_eq!(
+            services(InitSystem::OpenRC, &root),
+            Some(Services {
+                running: Some(2),
+                failed: Some(1)
+            })
+        );
+
+        write("etc/service/sshd/supervise/stat", "run\n");
+        write("etc/service/ntpd/supervise/stat", "down\n");
+        assert_eq!(services(InitSystem::Runit, &root).unwrap().running, Some(1));
+
+        write("proc/1/comm", "upstart\n");
+        assert!(init_system(&root).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
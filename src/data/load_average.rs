@@ -0,0 +1,53 @@
+use libmacchina::traits::ReadoutError;
+
+/// Returns the 1, 5 and 15 minute load averages.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn load_average() -> Result<[f64; 3], ReadoutError> {
+    let loadavg = std::fs::read_to_string("/proc/loadavg")
+        .map_err(|e| ReadoutError::Other(format!("Failed to read /proc/loadavg: {e}")))?;
+
+    parse_loadavg(&loadavg)
+}
+
+/// Returns the 1, 5 and 15 minute load averages.
+#[cfg(all(unix, not(any(target_os = "linux", target_os = "android"))))]
+pub fn load_average() -> Result<[f64; 3], ReadoutError> {
+    let mut loads = [0.0; 3];
+
+    // SAFETY: the buffer holds the three elements getloadavg is asked for.
+    match unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) } {
+        3 => Ok(loads),
+        _ => Err(ReadoutError::Other(String::from("getloadavg failed"))),
+    }
+}
+
+/// Returns the 1, 5 and 15 minute load averages.
+#[cfg(not(unix))]
+pub fn load_average() -> Result<[f64; 3], ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+/// Parses the first three fields of `/proc/loadavg`.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+fn parse_loadavg(loadavg: &str) -> Result<[f64; 3], ReadoutError> {
+    let mut fields = loadavg.split_whitespace().map(|f| f.parse::<f64>().ok());
+
+    match (fields.next(), fields.next(), fields.next()) {
+        (Some(Some(one)), Some(Some(five)), Some(Some(fifteen))) => Ok([one, five, fifteen]),
+        _ => Err(ReadoutError::Other(String::from(
+            "Failed to parse /proc/loadavg",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loadavg() {
+        let loads = parse_loadavg("0.52 0.58 1.59 1/467 12345\n").ok();
+        assert_eq!(loads, Some([0.52, 0.58, 1.59]));
+        assert!(parse_loadavg("0.52 0.58").is_err());
+    }
+}
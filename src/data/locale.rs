@@ -0,0 +1,184 @@
+use libmacchina::traits::ReadoutError;
+use std::path::Path;
+
+/// The format of the DateTime readout, unless one is configured.
+pub const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+fn var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+/// Returns the value of `LANG` in the contents of a `locale.conf` file, e.g.
+/// `LANG="en_US.UTF-8"`.
+fn parse_locale_conf(locale_conf: &str) -> Option<String> {
+    locale_conf
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("LANG="))
+        .map(|lang| lang.trim_matches(|c| c == '"' || c == '\'').to_owned())
+        .filter(|lang| !lang.is_empty())
+}
+
+/// Returns the locale, taken from `LC_ALL` or `LANG`, or from the system-wide
+/// `locale.conf` found under the given root, which is `/` outside of tests.
+pub fn locale(root: &Path) -> Result<String, ReadoutError> {
+    const LOCALE_CONFS: &[&str] = &["etc/locale.conf", "etc/default/locale"];
+
+    var("LC_ALL")
+        .or_else(|| var("LANG"))
+        .or_else(|| {
+            LOCALE_CONFS.iter().find_map(|path| {
+                std::fs::read_to_string(root.join(path))
+                    .ok()
+                    .and_then(|conf| parse_locale_conf(&conf))
+            })
+        })
+        .ok_or_else(|| {
+            ReadoutError::Other(String::from(
+                "Neither LC_ALL, LANG nor locale.conf specify a locale.",
+            ))
+        })
+}
+
+/// Returns the name of the timezone stored at the given path, e.g.
+/// `/usr/share/zoneinfo/Europe/Paris` becomes `Europe/Paris`.
+fn zone_name(path: &str) -> Option<String> {
+    path.split_once("zoneinfo/")
+        .map(|(_, name)| name.to_owned())
+        .filter(|name| !name.is_empty())
+}
+
+/// Returns the timezone, taken from `TZ`, or from the system-wide
+/// configuration found under the given root, which is `/` outside of tests.
+pub fn timezone(root: &Path) -> Result<String, ReadoutError> {
+    if let Some(tz) = var("TZ") {
+        // TZ may name a zone, e.g. ":Europe/Paris", or point to a zone file.
+        let tz = tz.trim_start_matches(':');
+        return Ok(zone_name(tz).unwrap_or_else(|| tz.to_owned()));
+    }
+
+    system_timezone(root).ok_or_else(|| {
+        ReadoutError::Other(String::from(
+            "Neither TZ, /etc/localtime nor /etc/timezone specify a timezone.",
+        ))
+    })
+}
+
+/// Returns the timezone from the target of the `/etc/localtime` link, or from
+/// `/etc/timezone` when `/etc/localtime` is a copy of the zone file instead.
+fn system_timezone(root: &Path) -> Option<String> {
+    std::fs::read_link(root.join("etc/localtime"))
+        .ok()
+        .and_then(|target| zone_name(&target.to_string_lossy()))
+        .or_else(|| {
+            std::fs::read_to_string(root.join("etc/timezone"))
+                .ok()
+                .map(|tz| tz.trim().to_owned())
+                .filter(|tz| !tz.is_empty())
+        })
+}
+
+/// Returns the current local date and time, formatted through `strftime(3)`.
+#[cfg(unix)]
+pub fn datetime(format: &str) -> Result<String, ReadoutError> {
+    // SAFETY: time accepts a null pointer, in which case it only returns.
+    let now = unsafe { libc::time(std::ptr::null_mut()) };
+    format_time(format, now as i64)
+}
+
+/// Returns the current local date and time, formatted through `strftime(3)`.
+#[cfg(not(unix))]
+pub fn datetime(_format: &str) -> Result<String, ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+/// Formats the given UNIX timestamp as a local date and time, through
+/// `strftime(3)`.
+#[cfg(unix)]
+pub fn format_time(format: &str, timestamp: i64) -> Result<String, ReadoutError> {
+    use std::ffi::CString;
+
+    let format = CString::new(format)
+        .map_err(|_| ReadoutError::Other(String::from("The date format contains a NUL byte.")))?;
+
+    // SAFETY: localtime_r only writes to the given structure.
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    let timestamp = timestamp as libc::time_t;
+    if unsafe { libc::localtime_r(&timestamp, &mut tm) }.is_null() {
+        return Err(ReadoutError::Other(String::from(
+            "Failed to determine the local time.",
+        )));
+    }
+
+    let mut buffer = [0u8; 256];
+
+    // SAFETY: strftime writes at most as many bytes as the buffer holds.
+    let len = unsafe {
+        libc::strftime(
+            buffer.as_mut_ptr().cast(),
+            buffer.len(),
+            format.as_ptr(),
+            &tm,
+        )
+    };
+
+    match len {
+        0 => Err(ReadoutError::Other(String::from(
+            "The date format produced no output, or too much of it.",
+        ))),
+        len => Ok(String::from_utf8_lossy(&buffer[..len]).into_owned()),
+    }
+}
+
+/// Formats the given UNIX timestamp as a local date and time, through
+/// `strftime(3)`.
+#[cfg(not(unix))]
+pub fn format_time(_format: &str, _timestamp: i64) -> Result<String, ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_and_timezone() {
+        assert_eq!(
+            parse_locale_conf("# generated\nLANG=\"de_DE.UTF-8\"\nLC_TIME=en_GB.UTF-8\n"),
+            Some(String::from("de_DE.UTF-8"))
+        );
+        assert_eq!(parse_locale_conf("LC_TIME=en_GB.UTF-8\n"), None);
+
+        assert_eq!(
+            zone_name("/usr/share/zoneinfo/America/Argentina/Buenos_Aires"),
+            Some(String::from("America/Argentina/Buenos_Aires"))
+        );
+        assert_eq!(zone_name("/etc/localtime"), None);
+
+        let root = std::env::temp_dir().join(format!("macchina-tz-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("etc")).unwrap();
+        assert_eq!(system_timezone(&root), None);
+
+        // /etc/localtime is a copy of the zone file, e.g. in Debian containers.
+        std::fs::write(root.join("etc/localtime"), "TZif2").unwrap();
+        std::fs::write(root.join("etc/timezone"), "Europe/Paris\n").unwrap();
+        assert_eq!(system_timezone(&root), Some(String::from("Europe/Paris")));
+
+        #[cfg(unix)]
+        {
+            std::fs::remove_file(root.join("etc/localtime")).unwrap();
+            std::os::unix::fs::symlink(
+                "/usr/share/zoneinfo/Asia/Tokyo",
+                root.join("etc/localtime"),
+            )
+            .unwrap();
+            assert_eq!(system_timezone(&root), Some(String::from("Asia/Tokyo")));
+        }
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        if cfg!(unix) {
+            assert_eq!(datetime("%Y").ok().map(|year| year.len()), Some(4));
+            assert!(datetime("").is_err());
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cli::Opt;
+use crate::cli::{Opt, Section};
 use crate::theme::Theme;
 use clap::{Parser, ValueEnum};
 use libmacchina::traits::GeneralReadout as _;
@@ -6,19 +6,34 @@ use libmacchina::traits::{ReadoutError, ShellFormat, ShellKind};
 use libmacchina::{BatteryReadout, GeneralReadout, KernelReadout, MemoryReadout, PackageReadout};
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span, Text};
+use serde::de::{self, Deserializer};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::Display;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+mod init;
+mod load_average;
+mod locale;
+pub mod network;
+mod swap;
+pub mod temperature;
+pub mod throughput;
+mod users;
+mod virtualization;
 
 /// This enum contains all the possible keys, e.g. _Host_, _Machine_, _Kernel_, etc.
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Parser, ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Parser, ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 #[clap(rename_all = "kebab-case")]
 pub enum ReadoutKey {
     Host,
     Machine,
+    Virtualization,
     Kernel,
+    Init,
     Distribution,
     OperatingSystem,
     DesktopEnvironment,
@@ -26,13 +41,23 @@ pub enum ReadoutKey {
     Packages,
     Shell,
     Terminal,
+    Users,
+    Session,
     LocalIP,
+    Network,
+    Throughput,
     Backlight,
     Resolution,
     Uptime,
+    Locale,
+    Timezone,
+    DateTime,
     Processor,
     ProcessorLoad,
+    LoadAverage,
+    Temperature,
     Memory,
+    Swap,
     Battery,
     GPU,
     DiskSpace,
@@ -43,7 +68,9 @@ impl Display for ReadoutKey {
         match *self {
             Self::Host => write!(f, "Host"),
             Self::Machine => write!(f, "Machine"),
+            Self::Virtualization => write!(f, "Virtualization"),
             Self::Kernel => write!(f, "Kernel"),
+            Self::Init => write!(f, "Init"),
             Self::Distribution => write!(f, "Distribution"),
             Self::OperatingSystem => write!(f, "OperatingSystem"),
             Self::DesktopEnvironment => write!(f, "DesktopEnvironment"),
@@ -51,13 +78,23 @@ impl Display for ReadoutKey {
             Self::Packages => write!(f, "Packages"),
             Self::Shell => write!(f, "Shell"),
             Self::Terminal => write!(f, "Terminal"),
+            Self::Users => write!(f, "Users"),
+            Self::Session => write!(f, "Session"),
             Self::LocalIP => write!(f, "LocalIP"),
+            Self::Network => write!(f, "Network"),
+            Self::Throughput => write!(f, "Throughput"),
             Self::Backlight => write!(f, "Backlight"),
             Self::Resolution => write!(f, "Resolution"),
             Self::Uptime => write!(f, "Uptime"),
+            Self::Locale => write!(f, "Locale"),
+            Self::Timezone => write!(f, "Timezone"),
+            Self::DateTime => write!(f, "DateTime"),
             Self::Processor => write!(f, "Processor"),
             Self::ProcessorLoad => write!(f, "ProcessorLoad"),
+            Self::LoadAverage => write!(f, "LoadAverage"),
+            Self::Temperature => write!(f, "Temperature"),
             Self::Memory => write!(f, "Memory"),
+            Self::Swap => write!(f, "Swap"),
             Self::Battery => write!(f, "Battery"),
             Self::GPU => write!(f, "GPU"),
             Self::DiskSpace => write!(f, "DiskSpace"),
@@ -65,6 +102,79 @@ impl Display for ReadoutKey {
     }
 }
 
+/// Alternative names that readouts can be referred to by.
+const READOUT_ALIASES: &[(&str, ReadoutKey)] = &[
+    ("cpu", ReadoutKey::Processor),
+    ("cpuload", ReadoutKey::ProcessorLoad),
+    ("loadavg", ReadoutKey::LoadAverage),
+    ("temp", ReadoutKey::Temperature),
+    ("ram", ReadoutKey::Memory),
+    ("virt", ReadoutKey::Virtualization),
+    ("ip", ReadoutKey::LocalIP),
+    ("de", ReadoutKey::DesktopEnvironment),
+    ("wm", ReadoutKey::WindowManager),
+    ("disk", ReadoutKey::DiskSpace),
+    ("os", ReadoutKey::OperatingSystem),
+    ("distro", ReadoutKey::Distribution),
+];
+
+fn normalize_readout_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Returns the name of the readout that most closely resembles the given one.
+fn suggest_readout_name(name: &str) -> Option<String> {
+    let names = ReadoutKey::value_variants()
+        .iter()
+        .map(|k| (normalize_readout_name(&k.to_string()), *k))
+        .chain(READOUT_ALIASES.iter().map(|(a, k)| (a.to_string(), *k)));
+
+    names
+        .map(|(candidate, key)| (strsim::levenshtein(name, &candidate), key))
+        .filter(|(distance, _)| *distance <= (name.len() / 3).max(2))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, key)| key.to_string())
+}
+
+impl FromStr for ReadoutKey {
+    type Err = String;
+
+    /// Readouts are matched regardless of casing, dashes, underscores and
+    /// spaces, and can also be referred to by their aliases.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = normalize_readout_name(s);
+
+        if let Some(key) = ReadoutKey::value_variants()
+            .iter()
+            .find(|k| normalize_readout_name(&k.to_string()) == name)
+        {
+            return Ok(*key);
+        }
+
+        if let Some((_, key)) = READOUT_ALIASES.iter().find(|(alias, _)| *alias == name) {
+            return Ok(*key);
+        }
+
+        match suggest_readout_name(&name) {
+            Some(hint) => Err(format!("unknown readout \"{s}\", did you mean \"{hint}\"?")),
+            None => Err(format!("unknown readout \"{s}\"")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ReadoutKey {
+    fn deserialize<D>(deserializer: D) -> Result<ReadoutKey, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Readout<'a>(pub ReadoutKey, pub Result<Text<'a>, ReadoutError>);
 
@@ -92,7 +202,15 @@ fn colored_glyphs(glyph: &str, blocks: usize) -> String {
         .join(" ")
 }
 
-fn create_bar<'a>(theme: &Theme, blocks: usize) -> Line<'a> {
+fn create_bar<'a>(theme: &Theme, readout_key: ReadoutKey, blocks: usize) -> Line<'a> {
+    let key_color = theme.key_color(&readout_key);
+    let value_style = theme.value_style(&readout_key);
+    let filled_style = value_style.fg(theme.get_bar().get_filled_color(key_color));
+    let empty_style = match theme.get_bar().get_empty_color() {
+        Some(color) => value_style.fg(color),
+        None => value_style,
+    };
+
     if theme.get_bar().are_delimiters_hidden() {
         let mut span_vector = vec![Span::raw(""), Span::raw("")];
 
@@ -105,20 +223,27 @@ fn create_bar<'a>(theme: &Theme, blocks: usize) -> Line<'a> {
             span_vector[0].content = Cow::from(format!("{glyphs} "));
         }
 
-        span_vector[0].style = Style::default().fg(theme.get_key_color());
+        span_vector[0].style = filled_style;
         span_vector[1].content = Cow::from(colored_glyphs(glyph, 10 - blocks));
+        span_vector[1].style = empty_style;
 
-        if theme.get_key_color() == Color::White {
+        if key_color == Color::White {
             span_vector[1].content = Cow::from(span_vector[1].content.replace(glyph, " "));
         }
         return Line::from(span_vector);
     }
 
     let mut span_vector = vec![
-        Span::raw(format!("{} ", theme.get_bar().get_symbol_open())),
+        Span::styled(
+            format!("{} ", theme.get_bar().get_symbol_open()),
+            value_style,
+        ),
         Span::raw(""),
         Span::raw(""),
-        Span::raw(format!(" {}", theme.get_bar().get_symbol_close())),
+        Span::styled(
+            format!(" {}", theme.get_bar().get_symbol_close()),
+            value_style,
+        ),
     ];
 
     let glyph = theme.get_bar().get_glyph();
@@ -129,26 +254,122 @@ fn create_bar<'a>(theme: &Theme, blocks: usize) -> Line<'a> {
     } else {
         span_vector[1].content = Cow::from(format!("{glyphs} "));
     }
-    span_vector[1].style = Style::default().fg(theme.get_key_color());
+    span_vector[1].style = filled_style;
 
     span_vector[2].content = Cow::from(colored_glyphs(glyph, 10 - blocks));
-    if theme.get_key_color() == Color::White {
+    span_vector[2].style = empty_style;
+    if key_color == Color::White {
         span_vector[2].content = Cow::from(span_vector[2].content.replace(glyph, " "));
     }
     Line::from(span_vector)
 }
 
+/// Readouts that are displayed when `show` is unset; the others have to be
+/// listed explicitly.
+const DEFAULT_READOUTS: &[ReadoutKey] = &[
+    ReadoutKey::Host,
+    ReadoutKey::Machine,
+    ReadoutKey::Kernel,
+    ReadoutKey::Distribution,
+    ReadoutKey::OperatingSystem,
+    ReadoutKey::DesktopEnvironment,
+    ReadoutKey::WindowManager,
+    ReadoutKey::Packages,
+    ReadoutKey::Shell,
+    ReadoutKey::Terminal,
+    ReadoutKey::LocalIP,
+    ReadoutKey::Backlight,
+    ReadoutKey::Resolution,
+    ReadoutKey::Uptime,
+    ReadoutKey::Processor,
+    ReadoutKey::ProcessorLoad,
+    ReadoutKey::Memory,
+    ReadoutKey::Battery,
+    ReadoutKey::GPU,
+    ReadoutKey::DiskSpace,
+];
+
 pub fn should_display(opt: &Opt) -> Vec<ReadoutKey> {
     if let Some(shown) = opt.show.to_owned() {
         return shown;
     }
 
-    let keys: Vec<ReadoutKey> = ReadoutKey::value_variants()
-        .iter()
-        .map(|f| ReadoutKey::from_str(&f.to_string(), true).unwrap_or(*f))
-        .collect();
+    DEFAULT_READOUTS.to_vec()
+}
+
+/// Groups the readouts that should be displayed into sections, which consist
+/// of a single untitled section unless `[[section]]` tables were specified.
+pub fn get_sections(opt: &Opt) -> Vec<Section> {
+    let mut sections = match &opt.section {
+        Some(sections) if !sections.is_empty() => sections.to_owned(),
+        _ => vec![Section {
+            title: None,
+            show: should_display(opt),
+        }],
+    };
+
+    if let Some(hidden) = &opt.hide {
+        for section in sections.iter_mut() {
+            section.show.retain(|k| !hidden.contains(k));
+        }
+    }
 
-    keys
+    sections
+}
+
+/// Readouts that are redacted by `--redact`, unless specified otherwise.
+const DEFAULT_REDACTED_READOUTS: &[ReadoutKey] = &[
+    ReadoutKey::Host,
+    ReadoutKey::Users,
+    ReadoutKey::LocalIP,
+    ReadoutKey::Network,
+    ReadoutKey::DiskSpace,
+];
+
+/// Returns whether the value of the given readout should be redacted.
+pub fn should_redact(opt: &Opt, readout_key: ReadoutKey) -> bool {
+    if !opt.redact {
+        return false;
+    }
+
+    match &opt.redact_readouts {
+        Some(readouts) => readouts.contains(&readout_key),
+        None => DEFAULT_REDACTED_READOUTS.contains(&readout_key),
+    }
+}
+
+/// Redacts the given value if the readout it belongs to should be redacted.
+fn redact_if(opt: &Opt, readout_key: ReadoutKey, value: &str) -> String {
+    use crate::format::redact;
+
+    if should_redact(opt, readout_key) {
+        return redact(value, opt.redact_keep_edges);
+    }
+
+    value.to_string()
+}
+
+/// Fills the `{user}` and `{host}` placeholders of the given template with
+/// the values that make up the Host readout.
+pub fn fill_placeholders(template: &str, opt: &Opt) -> String {
+    if !template.contains('{') {
+        return template.to_string();
+    }
+
+    let general_readout = GeneralReadout::new();
+    let mut output = template.to_string();
+
+    if output.contains("{user}") {
+        let username = general_readout.username().unwrap_or_default();
+        output = output.replace("{user}", &redact_if(opt, ReadoutKey::Host, &username));
+    }
+
+    if output.contains("{host}") {
+        let hostname = general_readout.hostname().unwrap_or_default();
+        output = output.replace("{host}", &redact_if(opt, ReadoutKey::Host, &hostname));
+    }
+
+    output
 }
 
 pub fn get_all_readouts<'a>(
@@ -161,7 +382,7 @@ pub fn get_all_readouts<'a>(
 
     for readout_key in should_display {
         match readout_key {
-            ReadoutKey::Host => handle_readout_host(&mut readout_values, &general_readout),
+            ReadoutKey::Host => handle_readout_host(&mut readout_values, &general_readout, opt),
             ReadoutKey::Machine => handle_readout_machine(&mut readout_values, &general_readout),
             ReadoutKey::Kernel => handle_readout_kernel(&mut readout_values, opt),
             ReadoutKey::OperatingSystem => {
@@ -199,18 +420,46 @@ pub fn get_all_readouts<'a>(
             ReadoutKey::DiskSpace => {
                 handle_readout_disk_space(&mut readout_values, &general_readout, theme, opt)
             }
+            ReadoutKey::Swap => handle_readout_swap(&mut readout_values, theme, opt),
+            ReadoutKey::LoadAverage => {
+                handle_readout_load_average(&mut readout_values, &general_readout, opt)
+            }
+            ReadoutKey::Temperature => handle_readout_temperature(&mut readout_values, opt),
+            ReadoutKey::Network => handle_readout_network(&mut readout_values, opt),
+            ReadoutKey::Throughput => handle_readout_throughput(&mut readout_values, opt),
+            ReadoutKey::Locale => handle_readout_locale(&mut readout_values),
+            ReadoutKey::Timezone => handle_readout_timezone(&mut readout_values),
+            ReadoutKey::DateTime => handle_readout_datetime(&mut readout_values, opt),
+            ReadoutKey::Init => handle_readout_init(&mut readout_values, opt),
+            ReadoutKey::Virtualization => handle_readout_virtualization(&mut readout_values),
+            ReadoutKey::Users => handle_readout_users(&mut readout_values, opt),
+            ReadoutKey::Session => handle_readout_session(&mut readout_values, opt),
         };
     }
 
+    crate::replace::apply(
+        &mut readout_values,
+        opt.replace.as_deref().unwrap_or_default(),
+        opt.tidy,
+    );
+
     readout_values
 }
 
 // READOUT HANDLERS
-fn handle_readout_host(readout_values: &mut Vec<Readout>, general_readout: &GeneralReadout) {
+fn handle_readout_host(
+    readout_values: &mut Vec<Readout>,
+    general_readout: &GeneralReadout,
+    opt: &Opt,
+) {
     use crate::format::host as format_host;
 
     match (general_readout.username(), general_readout.hostname()) {
-        (Ok(u), Ok(h)) => readout_values.push(Readout::new(ReadoutKey::Host, format_host(&u, &h))),
+        (Ok(u), Ok(h)) => {
+            let u = redact_if(opt, ReadoutKey::Host, &u);
+            let h = redact_if(opt, ReadoutKey::Host, &h);
+            readout_values.push(Readout::new(ReadoutKey::Host, format_host(&u, &h)))
+        }
         (Err(e), _) | (_, Err(e)) => readout_values.push(Readout::new_err(ReadoutKey::Host, e)),
     }
 }
@@ -276,12 +525,169 @@ fn handle_readout_local_ip(readout_values: &mut Vec<Readout>, opt: &Opt) {
     use libmacchina::traits::NetworkReadout as _;
     use libmacchina::NetworkReadout;
 
+    let configured = opt.interface.as_deref().unwrap_or_default();
+    let (interface, _) = network::local_ip_interface(configured, Path::new("/"));
+
     let network_readout = NetworkReadout::new();
-    match network_readout.logical_address(opt.interface.as_deref()) {
-        Ok(s) => readout_values.push(Readout::new(ReadoutKey::LocalIP, s)),
+    match network_readout.logical_address(interface.as_deref()) {
+        Ok(s) => readout_values.push(Readout::new(
+            ReadoutKey::LocalIP,
+            redact_if(opt, ReadoutKey::LocalIP, &s),
+        )),
         Err(e) => readout_values.push(Readout::new_err(ReadoutKey::LocalIP, e)),
     }
 }
+
+fn handle_readout_network(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    use crate::format::network_interface as format_interface;
+
+    let interfaces = match network::interfaces() {
+        Ok(interfaces) => interfaces,
+        Err(e) => return readout_values.push(Readout::new_err(ReadoutKey::Network, e)),
+    };
+
+    let patterns = opt.network_interfaces.as_deref().unwrap_or_default();
+    let interfaces = network::filter(interfaces, patterns);
+
+    if interfaces.is_empty() {
+        return readout_values.push(Readout::new_err(
+            ReadoutKey::Network,
+            ReadoutError::Warning(String::from("No network interface is up.")),
+        ));
+    }
+
+    // each interface gets its own row, like GPUs do.
+    for interface in interfaces {
+        let addresses: Vec<String> = interface
+            .addresses()
+            .iter()
+            .map(|a| redact_if(opt, ReadoutKey::Network, &a.to_string()))
+            .collect();
+
+        let mut details = vec![];
+        if let Some(mac) = interface.mac.as_ref().filter(|_| opt.network_mac) {
+            details.push(redact_if(opt, ReadoutKey::Network, mac));
+        }
+
+        if let Some(speed) = interface.speed.filter(|_| opt.network_speed) {
+            details.push(format!("{speed} Mb/s"));
+        }
+
+        readout_values.push(Readout::new(
+            ReadoutKey::Network,
+            format_interface(&interface.name, &addresses, &details),
+        ));
+    }
+}
+
+fn handle_readout_throughput(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    use crate::format::throughput as format_throughput;
+
+    /// The interval, in milliseconds, between the two samples.
+    const DEFAULT_INTERVAL: u64 = 500;
+
+    let interfaces = match network::interfaces() {
+        Ok(interfaces) => interfaces,
+        Err(e) => return readout_values.push(Readout::new_err(ReadoutKey::Throughput, e)),
+    };
+
+    let patterns = opt.network_interfaces.as_deref().unwrap_or_default();
+    let interfaces = network::filter(interfaces, patterns);
+
+    if interfaces.is_empty() {
+        return readout_values.push(Readout::new_err(
+            ReadoutKey::Throughput,
+            ReadoutError::Warning(String::from("No network interface is up.")),
+        ));
+    }
+
+    let interval = Duration::from_millis(opt.throughput_interval.unwrap_or(DEFAULT_INTERVAL));
+    let samples = match throughput::sample(interval) {
+        Ok(samples) => samples,
+        Err(e) => return readout_values.push(Readout::new_err(ReadoutKey::Throughput, e)),
+    };
+
+    let len = readout_values.len();
+    for interface in interfaces {
+        if let Some(sample) = samples.get(&interface.name) {
+            let total = opt.throughput_totals.then_some(sample.total);
+            readout_values.push(Readout::new(
+                ReadoutKey::Throughput,
+                format_throughput(&interface.name, sample.rate, total),
+            ));
+        }
+    }
+
+    if readout_values.len() == len {
+        readout_values.push(Readout::new_err(
+            ReadoutKey::Throughput,
+            ReadoutError::Warning(String::from(
+                "None of the network interfaces that are up could be sampled.",
+            )),
+        ));
+    }
+}
+
+fn handle_readout_locale(readout_values: &mut Vec<Readout>) {
+    match locale::locale(Path::new("/")) {
+        Ok(s) => readout_values.push(Readout::new(ReadoutKey::Locale, s)),
+        Err(e) => readout_values.push(Readout::new_err(ReadoutKey::Locale, e)),
+    }
+}
+
+fn handle_readout_timezone(readout_values: &mut Vec<Readout>) {
+    match locale::timezone(Path::new("/")) {
+        Ok(s) => readout_values.push(Readout::new(ReadoutKey::Timezone, s)),
+        Err(e) => readout_values.push(Readout::new_err(ReadoutKey::Timezone, e)),
+    }
+}
+
+fn handle_readout_datetime(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    let format = opt
+        .datetime_format
+        .as_deref()
+        .unwrap_or(locale::DEFAULT_DATETIME_FORMAT);
+
+    match locale::datetime(format) {
+        Ok(s) => readout_values.push(Readout::new(ReadoutKey::DateTime, s)),
+        Err(e) => readout_values.push(Readout::new_err(ReadoutKey::DateTime, e)),
+    }
+}
+
+fn handle_readout_init(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    use crate::format::init as format_init;
+
+    let root = Path::new("/");
+    match init::init_system(root) {
+        Ok(init_system) => {
+            let services = init::services(init_system, root)
+                .filter(|_| opt.init_services)
+                .unwrap_or_default();
+
+            readout_values.push(Readout::new(
+                ReadoutKey::Init,
+                format_init(&init_system.to_string(), services.running, services.failed),
+            ))
+        }
+        Err(e) => readout_values.push(Readout::new_err(ReadoutKey::Init, e)),
+    }
+}
+
+fn handle_readout_virtualization(readout_values: &mut Vec<Readout>) {
+    use crate::format::virtualization as format_virtualization;
+
+    let container_env = std::env::var("container").ok();
+    let virtualization = virtualization::detect(Path::new("/"), container_env.as_deref());
+
+    readout_values.push(Readout::new(
+        ReadoutKey::Virtualization,
+        format_virtualization(
+            virtualization.hypervisor.as_deref(),
+            virtualization.container.as_deref(),
+        ),
+    ));
+}
+
 fn handle_readout_terminal(readout_values: &mut Vec<Readout>, general_readout: &GeneralReadout) {
     match general_readout.terminal() {
         Ok(s) => readout_values.push(Readout::new(ReadoutKey::Terminal, s)),
@@ -289,6 +695,52 @@ fn handle_readout_terminal(readout_values: &mut Vec<Readout>, general_readout: &
     }
 }
 
+fn handle_readout_users(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    use crate::format::users as format_users;
+
+    match users::logins() {
+        Ok(logins) => {
+            let names: Vec<String> = users::unique_users(&logins)
+                .iter()
+                .map(|u| redact_if(opt, ReadoutKey::Users, u))
+                .collect();
+
+            if names.is_empty() {
+                return readout_values.push(Readout::new_err(
+                    ReadoutKey::Users,
+                    ReadoutError::Warning(String::from("No user is logged in.")),
+                ));
+            }
+
+            readout_values.push(Readout::new(ReadoutKey::Users, format_users(&names)))
+        }
+        Err(e) => readout_values.push(Readout::new_err(ReadoutKey::Users, e)),
+    }
+}
+
+fn handle_readout_session(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    use crate::format::session as format_session;
+
+    let kind = if users::is_ssh() { "SSH" } else { "Local" };
+    let tty = users::tty();
+
+    // the login time is that of the utmp entry of the current terminal.
+    let since = tty.as_ref().and_then(|tty| {
+        let login = users::logins().ok()?.into_iter().find(|l| &l.line == tty)?;
+        let format = opt
+            .datetime_format
+            .as_deref()
+            .unwrap_or(locale::DEFAULT_DATETIME_FORMAT);
+
+        locale::format_time(format, login.time).ok()
+    });
+
+    readout_values.push(Readout::new(
+        ReadoutKey::Session,
+        format_session(kind, tty.as_deref(), since.as_deref()),
+    ));
+}
+
 fn handle_readout_shell(
     readout_values: &mut Vec<Readout>,
     general_readout: &GeneralReadout,
@@ -345,12 +797,24 @@ fn handle_readout_backlight(
         }
         (Ok(b), true) => readout_values.push(Readout::new(
             ReadoutKey::Backlight,
-            create_bar(theme, crate::bars::num_to_blocks(b as u8)),
+            create_bar(
+                theme,
+                ReadoutKey::Backlight,
+                crate::bars::num_to_blocks(b as u8),
+            ),
         )),
         (Err(e), _) => readout_values.push(Readout::new_err(ReadoutKey::Backlight, e)),
     }
 }
 
+fn get_cpu_cores(general_readout: &GeneralReadout, opt: &Opt) -> Result<usize, ReadoutError> {
+    if opt.physical_cores {
+        general_readout.cpu_physical_cores()
+    } else {
+        general_readout.cpu_cores()
+    }
+}
+
 fn handle_readout_processor(
     readout_values: &mut Vec<Readout>,
     general_readout: &GeneralReadout,
@@ -359,13 +823,7 @@ fn handle_readout_processor(
     use crate::format::cpu as format_cpu;
     use crate::format::cpu_only as format_cpu_only;
 
-    let cores = {
-        if opt.physical_cores {
-            general_readout.cpu_physical_cores()
-        } else {
-            general_readout.cpu_cores()
-        }
-    };
+    let cores = get_cpu_cores(general_readout, opt);
 
     match (general_readout.cpu_model_name(), cores) {
         (Ok(m), Ok(c)) => {
@@ -387,12 +845,20 @@ fn handle_readout_processor_load(
             if u > 100 {
                 readout_values.push(Readout::new(
                     ReadoutKey::ProcessorLoad,
-                    create_bar(theme, crate::bars::num_to_blocks(100_u8)),
+                    create_bar(
+                        theme,
+                        ReadoutKey::ProcessorLoad,
+                        crate::bars::num_to_blocks(100_u8),
+                    ),
                 ))
             }
             readout_values.push(Readout::new(
                 ReadoutKey::ProcessorLoad,
-                create_bar(theme, crate::bars::num_to_blocks(u as u8)),
+                create_bar(
+                    theme,
+                    ReadoutKey::ProcessorLoad,
+                    crate::bars::num_to_blocks(u as u8),
+                ),
             ))
         }
         (Ok(u), _) => {
@@ -402,6 +868,84 @@ fn handle_readout_processor_load(
     }
 }
 
+/// Returns the color of a load average, based on how busy the cores are.
+fn load_color(load_per_core: f64) -> Color {
+    match load_per_core {
+        l if l < 0.7 => Color::Green,
+        l if l < 1.0 => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+fn handle_readout_load_average(
+    readout_values: &mut Vec<Readout>,
+    general_readout: &GeneralReadout,
+    opt: &Opt,
+) {
+    let loads = match load_average::load_average() {
+        Ok(loads) => loads,
+        Err(e) => return readout_values.push(Readout::new_err(ReadoutKey::LoadAverage, e)),
+    };
+
+    // the averages are only colored when they can be compared to the number
+    // of cores.
+    let cores = get_cpu_cores(general_readout, opt)
+        .ok()
+        .filter(|c| *c > 0)
+        .map(|c| c as f64);
+
+    let mut spans = vec![];
+    for (i, load) in loads.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(", "));
+        }
+
+        let value = match cores {
+            Some(cores) if opt.normalize_load_average => format!("{:.2}", load / cores),
+            _ => format!("{load:.2}"),
+        };
+
+        match cores {
+            Some(cores) => spans.push(Span::styled(
+                value,
+                Style::default().fg(load_color(load / cores)),
+            )),
+            None => spans.push(Span::raw(value)),
+        }
+    }
+
+    readout_values.push(Readout::new(ReadoutKey::LoadAverage, Line::from(spans)));
+}
+
+fn handle_readout_temperature(readout_values: &mut Vec<Readout>, opt: &Opt) {
+    use crate::format::temperature as format_temp;
+
+    let labels = opt.temperature_sensors.as_deref().unwrap_or_default();
+    let sensors = temperature::select(temperature::sensors(Path::new("/")), labels);
+    let unit = opt.temperature_unit.unwrap_or_default();
+
+    match sensors.as_slice() {
+        [] => readout_values.push(Readout::new_err(
+            ReadoutKey::Temperature,
+            ReadoutError::MetricNotAvailable,
+        )),
+        [sensor] => readout_values.push(Readout::new(
+            ReadoutKey::Temperature,
+            format_temp(sensor.celsius, unit),
+        )),
+        sensors => {
+            let temperatures: Vec<String> = sensors
+                .iter()
+                .map(|s| format!("{}: {}", s.label, format_temp(s.celsius, unit)))
+                .collect();
+            readout_values.push(Readout::new(
+                ReadoutKey::Temperature,
+                temperatures.join(", "),
+            ))
+        }
+    }
+}
+
 fn handle_readout_memory(readout_values: &mut Vec<Readout>, theme: &Theme, opt: &Opt) {
     use crate::format::memory as format_mem;
     use libmacchina::traits::MemoryReadout as _;
@@ -413,7 +957,7 @@ fn handle_readout_memory(readout_values: &mut Vec<Readout>, theme: &Theme, opt:
     match (total, used) {
         (Ok(total), Ok(used)) => {
             if theme.get_bar().is_visible() {
-                let bar = create_bar(theme, crate::bars::usage(used, total));
+                let bar = create_bar(theme, ReadoutKey::Memory, crate::bars::usage(used, total));
                 readout_values.push(Readout::new(ReadoutKey::Memory, bar))
             } else {
                 readout_values.push(Readout::new(
@@ -426,6 +970,29 @@ fn handle_readout_memory(readout_values: &mut Vec<Readout>, theme: &Theme, opt:
     }
 }
 
+fn handle_readout_swap(readout_values: &mut Vec<Readout>, theme: &Theme, opt: &Opt) {
+    use crate::format::memory as format_mem;
+
+    match swap::swap() {
+        Ok((0, _)) => readout_values.push(Readout::new_err(
+            ReadoutKey::Swap,
+            ReadoutError::Warning(String::from("No swap is configured on this system.")),
+        )),
+        Ok((total, used)) => {
+            if theme.get_bar().is_visible() {
+                let bar = create_bar(theme, ReadoutKey::Swap, crate::bars::usage(used, total));
+                readout_values.push(Readout::new(ReadoutKey::Swap, bar))
+            } else {
+                readout_values.push(Readout::new(
+                    ReadoutKey::Swap,
+                    format_mem(total, used, opt.swap_percentage),
+                ))
+            }
+        }
+        Err(e) => readout_values.push(Readout::new_err(ReadoutKey::Swap, e)),
+    }
+}
+
 fn handle_readout_battery(readout_values: &mut Vec<Readout>, theme: &Theme) {
     use crate::format::battery as format_bat;
     use libmacchina::traits::BatteryReadout as _;
@@ -439,7 +1006,7 @@ fn handle_readout_battery(readout_values: &mut Vec<Readout>, theme: &Theme) {
     match (percentage, state) {
         (Ok(p), Ok(s)) => {
             if theme.get_bar().is_visible() {
-                let bar = create_bar(theme, crate::bars::num_to_blocks(p));
+                let bar = create_bar(theme, key, crate::bars::num_to_blocks(p));
                 readout_values.push(Readout::new(key, bar));
             } else {
                 readout_values.push(Readout::new(key, format_bat(p, s)));
@@ -518,6 +1085,7 @@ fn handle_readout_disk_space(
     opt: &Opt,
 ) {
     use crate::format::disk_space as format_disk_space;
+    use crate::format::redact_home;
 
     let Some(disks) = opt.disks.to_owned() else {
         return;
@@ -528,9 +1096,19 @@ fn handle_readout_disk_space(
         match general_readout.disk_space(&disk_path) {
             Ok((used, total)) => {
                 if theme.get_bar().is_visible() {
-                    let bar = create_bar(theme, crate::bars::usage(used, total));
+                    let bar = create_bar(
+                        theme,
+                        ReadoutKey::DiskSpace,
+                        crate::bars::usage(used, total),
+                    );
                     readout_values.push(Readout::new(ReadoutKey::DiskSpace, bar))
                 } else {
+                    let disk_path = if should_redact(opt, ReadoutKey::DiskSpace) {
+                        redact_home(&disk_path, opt.redact_keep_edges)
+                    } else {
+                        disk_path
+                    };
+
                     readout_values.push(Readout::new(
                         ReadoutKey::DiskSpace,
                         format_disk_space(disk_path, used, total, opt.disk_space_percentage),
@@ -541,3 +1119,71 @@ fn handle_readout_disk_space(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readout_key_from_str() {
+        assert_eq!("LocalIP".parse(), Ok(ReadoutKey::LocalIP));
+        assert_eq!("local-ip".parse(), Ok(ReadoutKey::LocalIP));
+        assert_eq!("disk_space".parse(), Ok(ReadoutKey::DiskSpace));
+        assert_eq!("RAM".parse(), Ok(ReadoutKey::Memory));
+        assert_eq!("wm".parse(), Ok(ReadoutKey::WindowManager));
+
+        let err = "procesor".parse::<ReadoutKey>().unwrap_err();
+        assert!(err.contains("did you mean \"Processor\""));
+    }
+
+    #[test]
+    fn test_should_display_defaults() {
+        let opt = Opt::default();
+        let shown = should_display(&opt);
+
+        assert_eq!(shown, DEFAULT_READOUTS);
+        assert!(shown.contains(&ReadoutKey::Host));
+        assert!(!shown.contains(&ReadoutKey::Swap));
+        assert!(!shown.contains(&ReadoutKey::Users));
+    }
+
+    #[test]
+    fn test_should_redact() {
+        let mut opt = Opt::default();
+        assert!(!should_redact(&opt, ReadoutKey::Host));
+        assert_eq!(redact_if(&opt, ReadoutKey::Host, "user"), "user");
+
+        opt.redact = true;
+        for key in ReadoutKey::value_variants() {
+            assert_eq!(
+                should_redact(&opt, *key),
+                DEFAULT_REDACTED_READOUTS.contains(key)
+            );
+        }
+        assert!(should_redact(&opt, ReadoutKey::Host));
+        assert!(should_redact(&opt, ReadoutKey::LocalIP));
+        assert!(!should_redact(&opt, ReadoutKey::Kernel));
+        assert_eq!(redact_if(&opt, ReadoutKey::Host, "user"), "****");
+        assert_eq!(redact_if(&opt, ReadoutKey::Kernel, "6.1.0"), "6.1.0");
+
+        opt.redact_keep_edges = true;
+        assert_eq!(redact_if(&opt, ReadoutKey::LocalIP, "10.0.0.1"), "1******1");
+
+        opt.redact_readouts = Some(vec![ReadoutKey::Kernel]);
+        assert!(should_redact(&opt, ReadoutKey::Kernel));
+        assert!(!should_redact(&opt, ReadoutKey::Host));
+    }
+
+    #[test]
+    fn test_throughput_is_not_displayed_by_default() {
+        // sampling the throughput blocks for the sampling interval, which
+        // would slow down every run that doesn't ask for it.
+        assert!(!should_display(&Opt::default()).contains(&ReadoutKey::Throughput));
+
+        let opt = Opt {
+            show: Some(vec![ReadoutKey::Throughput]),
+            ..Default::default()
+        };
+        assert_eq!(should_display(&opt), vec![ReadoutKey::Throughput]);
+    }
+}
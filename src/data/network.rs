@@ -0,0 +1,367 @@
+use libmacchina::traits::ReadoutError;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+/// Interfaces that are hidden unless they're explicitly asked for.
+const HIDDEN_INTERFACES: &[&str] = &["docker*", "br-*", "veth*", "virbr*"];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Interface {
+    pub name: String,
+    pub up: bool,
+    pub loopback: bool,
+    pub addresses: Vec<IpAddr>,
+    pub mac: Option<String>,
+    /// The link speed, in megabits per second.
+    pub speed: Option<u64>,
+}
+
+impl Interface {
+    /// Returns the addresses of the interface, IPv4 first, leaving out the
+    /// link-local IPv6 ones.
+    pub fn addresses(&self) -> Vec<IpAddr> {
+        let is_link_local = |ip: &Ipv6Addr| (ip.segments()[0] & 0xffc0) == 0xfe80;
+        let ipv4 = self.addresses.iter().filter(|a| a.is_ipv4());
+        let ipv6 = self
+            .addresses
+            .iter()
+            .filter(|a| matches!(a, IpAddr::V6(ip) if !is_link_local(ip)));
+        ipv4.chain(ipv6).copied().collect()
+    }
+}
+
+/// Returns every network interface, along with its addresses.
+#[cfg(unix)]
+pub fn interfaces() -> Result<Vec<Interface>, ReadoutError> {
+    use std::ffi::CStr;
+
+    let mut addrs: *mut libc::ifaddrs = std::ptr::null_mut();
+
+    // SAFETY: getifaddrs allocates the list, which is freed below.
+    if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+        return Err(ReadoutError::Other(String::from("getifaddrs failed")));
+    }
+
+    let mut interfaces: Vec<Interface> = vec![];
+    let mut cursor = addrs;
+
+    while !cursor.is_null() {
+        // SAFETY: the entries of the list are valid until it's freed.
+        let ifa = unsafe { &*cursor };
+        cursor = ifa.ifa_next;
+
+        let name = unsafe { CStr::from_ptr(ifa.ifa_name) }
+            .to_string_lossy()
+            .into_owned();
+
+        let index = match interfaces.iter().position(|i| i.name == name) {
+            Some(index) => index,
+            None => {
+                let flags = ifa.ifa_flags as libc::c_int;
+                interfaces.push(Interface {
+                    up: flags & libc::IFF_UP != 0 && flags & libc::IFF_RUNNING != 0,
+                    loopback: flags & libc::IFF_LOOPBACK != 0,
+                    mac: read_sysfs(&name, "address").filter(|m| m != "00:00:00:00:00:00"),
+                    speed: read_sysfs(&name, "speed")
+                        .and_then(|s| s.parse::<u64>().ok())
+                        .filter(|s| *s > 0),
+                    name,
+                    ..Default::default()
+                });
+                interfaces.len() - 1
+            }
+        };
+
+        if ifa.ifa_addr.is_null() {
+            continue;
+        }
+
+        // SAFETY: the address is cast to the structure matching its family.
+        let address = match i32::from(unsafe { (*ifa.ifa_addr).sa_family }) {
+            libc::AF_INET => {
+                let addr = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in) };
+                IpAddr::V4(Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)))
+            }
+            libc::AF_INET6 => {
+                let addr = unsafe { &*(ifa.ifa_addr as *const libc::sockaddr_in6) };
+                IpAddr::V6(Ipv6Addr::from(addr.sin6_addr.s6_addr))
+            }
+            _ => continue,
+        };
+
+        interfaces[index].addresses.push(address);
+    }
+
+    // SAFETY: the list was allocated by getifaddrs.
+    unsafe { libc::freeifaddrs(addrs) };
+
+    Ok(interfaces)
+}
+
+/// Returns every network interface, along with its addresses.
+#[cfg(not(unix))]
+pub fn interfaces() -> Result<Vec<Interface>, ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+/// Reads an attribute of an interface from `/sys/class/net`.
+#[cfg(unix)]
+fn read_sysfs(interface: &str, attribute: &str) -> Option<String> {
+    let path = std::path::Path::new("/sys/class/net")
+        .join(interface)
+        .join(attribute);
+
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+}
+
+/// The route flag marking a route as usable.
+const RTF_UP: u32 = 0x1;
+
+/// Returns the interface of the usable default route with the lowest metric
+/// in `/proc/net/route`, whose fields are hexadecimal.
+fn parse_route(route: &str) -> Option<String> {
+    route
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [iface, destination, _, flags, _, _, metric, mask, ..] = fields[..] else {
+                return None;
+            };
+
+            let flags = u32::from_str_radix(flags, 16).ok()?;
+            if destination != "00000000" || mask != "00000000" || flags & RTF_UP == 0 {
+                return None;
+            }
+
+            Some((metric.parse::<u32>().ok()?, iface.to_owned()))
+        })
+        .min()
+        .map(|(_, iface)| iface)
+}
+
+/// Returns the interface of the usable default route with the lowest metric
+/// in `/proc/net/ipv6_route`, leaving out the unreachable routes that the
+/// kernel attaches to the loopback interface.
+fn parse_ipv6_route(route: &str) -> Option<String> {
+    route
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [destination, prefix, _, _, _, metric, _, _, flags, iface, ..] = fields[..] else {
+                return None;
+            };
+
+            let flags = u32::from_str_radix(flags, 16).ok()?;
+            let is_default = prefix == "00" && destination.chars().all(|c| c == '0');
+            if !is_default || iface == "lo" || flags & RTF_UP == 0 {
+                return None;
+            }
+
+            Some((u32::from_str_radix(metric, 16).ok()?, iface.to_owned()))
+        })
+        .min()
+        .map(|(_, iface)| iface)
+}
+
+/// Returns the interface carrying the default route, preferring the IPv4 one,
+/// from the routing tables found under the given root, which is `/` outside of
+/// tests.
+pub fn default_route_interface(root: &Path) -> Option<String> {
+    let read = |path: &str| std::fs::read_to_string(root.join(path)).ok();
+
+    read("proc/net/route")
+        .and_then(|route| parse_route(&route))
+        .or_else(|| read("proc/net/ipv6_route").and_then(|route| parse_ipv6_route(&route)))
+}
+
+/// Why an interface was chosen for the LocalIP readout.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    Configured,
+    FirstUp,
+    NoneUp,
+    DefaultRoute,
+    NoDefaultRoute,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::Configured => write!(f, "it is the configured interface"),
+            Reason::FirstUp => write!(f, "it is the first configured interface that is up"),
+            Reason::NoneUp => write!(
+                f,
+                "none of the configured interfaces are up, so the first one is used"
+            ),
+            Reason::DefaultRoute => write!(f, "it carries the default route"),
+            Reason::NoDefaultRoute => write!(
+                f,
+                "no interface is configured and no default route was found"
+            ),
+        }
+    }
+}
+
+/// Chooses the interface of the LocalIP readout. A single configured interface
+/// is used as is, a list of them is treated as an ordered fallback list, and
+/// the interface carrying the default route is used when none are configured.
+pub fn local_ip_interface(configured: &[String], root: &Path) -> (Option<String>, Reason) {
+    match configured {
+        [] => match default_route_interface(root) {
+            Some(iface) => (Some(iface), Reason::DefaultRoute),
+            None => (None, Reason::NoDefaultRoute),
+        },
+        [iface] => (Some(iface.to_owned()), Reason::Configured),
+        [first, ..] => {
+            let interfaces = interfaces().unwrap_or_default();
+            let is_up = |name: &String| {
+                interfaces
+                    .iter()
+                    .any(|i| &i.name == name && i.up && !i.addresses().is_empty())
+            };
+
+            match configured.iter().find(|name| is_up(name)) {
+                Some(iface) => (Some(iface.to_owned()), Reason::FirstUp),
+                None => (Some(first.to_owned()), Reason::NoneUp),
+            }
+        }
+    }
+}
+
+/// Matches the given name against a glob pattern, where `*` matches any
+/// number of characters and `?` matches exactly one.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    fn matches(pattern: &[char], name: &[char]) -> bool {
+        match (pattern.first(), name.first()) {
+            (None, None) => true,
+            (Some('*'), _) => {
+                matches(&pattern[1..], name) || (!name.is_empty() && matches(pattern, &name[1..]))
+            }
+            (Some('?'), Some(_)) => matches(&pattern[1..], &name[1..]),
+            (Some(p), Some(n)) if p == n => matches(&pattern[1..], &name[1..]),
+            _ => false,
+        }
+    }
+
+    matches(&pattern, &name)
+}
+
+/// Keeps the interfaces that are up and match one of the given patterns.
+/// Without patterns, loopback and container bridges are left out.
+pub fn filter(interfaces: Vec<Interface>, patterns: &[String]) -> Vec<Interface> {
+    interfaces
+        .into_iter()
+        .filter(|i| i.up)
+        .filter(|i| {
+            if patterns.is_empty() {
+                !i.loopback && !HIDDEN_INTERFACES.iter().any(|p| glob_match(p, &i.name))
+            } else {
+                patterns.iter().any(|p| glob_match(p, &i.name))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_interfaces() {
+        assert!(glob_match("eth*", "eth0"));
+        assert!(glob_match("wg?", "wg0"));
+        assert!(glob_match("*", "lo"));
+        assert!(!glob_match("wg?", "wg10"));
+        assert!(!glob_match("eth*", "enp3s0"));
+
+        let interface = |name: &str, up: bool, loopback: bool| Interface {
+            name: name.to_owned(),
+            up,
+            loopback,
+            ..Default::default()
+        };
+
+        let interfaces = vec![
+            interface("lo", true, true),
+            interface("eth0", true, false),
+            interface("eth1", false, false),
+            interface("docker0", true, false),
+            interface("wg0", true, false),
+        ];
+
+        let names = |interfaces: Vec<Interface>| -> Vec<String> {
+            interfaces.into_iter().map(|i| i.name).collect()
+        };
+
+        assert_eq!(names(filter(interfaces.clone(), &[])), ["eth0", "wg0"]);
+        assert_eq!(
+            names(filter(
+                interfaces,
+                &[String::from("docker*"), String::from("lo")]
+            )),
+            ["lo", "docker0"]
+        );
+    }
+
+    #[test]
+    fn test_interface_addresses() {
+        let interface = Interface {
+            addresses: vec![
+                "fe80::1".parse().unwrap(),
+                "2001:db8::2".parse().unwrap(),
+                "192.168.1.2".parse().unwrap(),
+            ],
+            ..Default::default()
+        };
+
+        let addresses: Vec<String> = interface
+            .addresses()
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        assert_eq!(addresses, ["192.168.1.2", "2001:db8::2"]);
+    }
+
+    #[test]
+    fn test_default_route_interface() {
+        let root = std::env::temp_dir().join(format!("macchina-procfs-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        };
+
+        let ipv6_route = "\
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+fe800000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001   wlan0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00000003   wlan0
+";
+        write("proc/net/ipv6_route", ipv6_route);
+        assert_eq!(default_route_interface(&root), Some(String::from("wlan0")));
+
+        let route = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+docker0\t000011AC\t00000000\t0001\t0\t0\t0\t0000FFFF\t0\t0\t0
+wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0
+eth0\t00000000\t0101A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+tun0\t00000000\t00000000\t0002\t0\t0\t0\t00000000\t0\t0\t0
+";
+        write("proc/net/route", route);
+        assert_eq!(default_route_interface(&root), Some(String::from("eth0")));
+
+        let (iface, reason) = local_ip_interface(&[], &root);
+        assert_eq!(iface, Some(String::from("eth0")));
+        assert_eq!(reason, Reason::DefaultRoute);
+
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(default_route_interface(&root), None);
+    }
+}
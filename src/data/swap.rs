@@ -0,0 +1,86 @@
+use libmacchina::traits::ReadoutError;
+
+/// Returns the total and used amount of swap, in kilobytes.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn swap() -> Result<(u64, u64), ReadoutError> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo")
+        .map_err(|e| ReadoutError::Other(format!("Failed to read /proc/meminfo: {e}")))?;
+
+    parse_meminfo(&meminfo)
+}
+
+/// Returns the total and used amount of swap, in kilobytes.
+#[cfg(target_os = "macos")]
+pub fn swap() -> Result<(u64, u64), ReadoutError> {
+    let output = std::process::Command::new("sysctl")
+        .args(["-n", "vm.swapusage"])
+        .output()
+        .map_err(|e| ReadoutError::Other(format!("Failed to run sysctl: {e}")))?;
+
+    parse_swapusage(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the total and used amount of swap, in kilobytes.
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+pub fn swap() -> Result<(u64, u64), ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+/// Parses the `SwapTotal` and `SwapFree` fields of `/proc/meminfo`.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+fn parse_meminfo(meminfo: &str) -> Result<(u64, u64), ReadoutError> {
+    let field = |name: &str| {
+        meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .and_then(|value| value.split_whitespace().next()?.parse::<u64>().ok())
+            .ok_or_else(|| ReadoutError::Other(format!("{name} is missing from /proc/meminfo")))
+    };
+
+    let total = field("SwapTotal")?;
+    let free = field("SwapFree")?;
+    Ok((total, total.saturating_sub(free)))
+}
+
+/// Parses the output of `sysctl -n vm.swapusage`, which looks like
+/// `total = 2048.00M  used = 1045.75M  free = 1002.25M  (encrypted)`.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_swapusage(swapusage: &str) -> Result<(u64, u64), ReadoutError> {
+    let field = |name: &str| {
+        let mut words = swapusage.split_whitespace();
+        words.find(|w| *w == name)?;
+        let value = words.nth(1)?;
+        let (number, unit) = value.split_at(value.len() - 1);
+        let number = number.parse::<f64>().ok()?;
+        let multiplier = match unit {
+            "K" => 1.0,
+            "M" => 1024.0,
+            "G" => 1024.0 * 1024.0,
+            _ => return None,
+        };
+
+        Some((number * multiplier) as u64)
+    };
+
+    match (field("total"), field("used")) {
+        (Some(total), Some(used)) => Ok((total, used)),
+        _ => Err(ReadoutError::Other(String::from(
+            "Failed to parse the output of sysctl",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_swap() {
+        let meminfo = "MemTotal:        6291456 kB\nSwapTotal:       2097152 kB\nSwapFree:        1572864 kB\n";
+        assert_eq!(parse_meminfo(meminfo).ok(), Some((2097152, 524288)));
+        assert!(parse_meminfo("MemTotal:        6291456 kB\n").is_err());
+
+        let swapusage = "total = 2048.00M  used = 512.00M  free = 1536.00M  (encrypted)";
+        assert_eq!(parse_swapusage(swapusage).ok(), Some((2097152, 524288)));
+    }
+}
@@ -0,0 +1,169 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// The labels of the sensors that report the temperature of the whole CPU
+/// package, in order of preference.
+const PACKAGE_SENSORS: &[&str] = &[
+    "package id 0",
+    "tctl",
+    "tdie",
+    "x86_pkg_temp",
+    "cpu_thermal",
+    "coretemp",
+    "k10temp",
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sensor {
+    pub label: String,
+    pub celsius: f64,
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+}
+
+/// Sensors report their temperature in millidegrees Celsius.
+fn read_millidegrees(path: &Path) -> Option<f64> {
+    read_trimmed(path)?.parse::<f64>().ok().map(|t| t / 1000.0)
+}
+
+fn sorted_entries(dir: &Path) -> Vec<std::path::PathBuf> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|e| e.path()).collect())
+        .unwrap_or_default();
+    entries.sort();
+    entries
+}
+
+/// Enumerates the `temp*_input` files of `/sys/class/hwmon`, which are
+/// labeled after their `temp*_label`, or the name of their chip otherwise.
+fn hwmon_sensors(root: &Path) -> Vec<Sensor> {
+    let mut sensors = vec![];
+
+    for chip in sorted_entries(&root.join("sys/class/hwmon")) {
+        let name = read_trimmed(&chip.join("name")).unwrap_or_default();
+
+        for input in sorted_entries(&chip) {
+            let Some(file_name) = input.file_name().and_then(|f| f.to_str()) else {
+                continue;
+            };
+
+            let Some(prefix) = file_name
+                .strip_suffix("_input")
+                .filter(|p| p.starts_with("temp"))
+            else {
+                continue;
+            };
+
+            if let Some(celsius) = read_millidegrees(&input) {
+                let label = read_trimmed(&chip.join(format!("{prefix}_label")))
+                    .unwrap_or_else(|| name.to_owned());
+                sensors.push(Sensor { label, celsius });
+            }
+        }
+    }
+
+    sensors
+}
+
+/// Enumerates `/sys/class/thermal/thermal_zone*`, which are labeled after
+/// their type.
+fn thermal_zone_sensors(root: &Path) -> Vec<Sensor> {
+    sorted_entries(&root.join("sys/class/thermal"))
+        .into_iter()
+        .filter(|zone| {
+            zone.file_name()
+                .and_then(|f| f.to_str())
+                .is_some_and(|f| f.starts_with("thermal_zone"))
+        })
+        .filter_map(|zone| {
+            Some(Sensor {
+                label: read_trimmed(&zone.join("type")).unwrap_or_default(),
+                celsius: read_millidegrees(&zone.join("temp"))?,
+            })
+        })
+        .collect()
+}
+
+/// Returns every temperature sensor found under the given root, which is
+/// `/` outside of tests.
+pub fn sensors(root: &Path) -> Vec<Sensor> {
+    let mut sensors = hwmon_sensors(root);
+    sensors.extend(thermal_zone_sensors(root));
+    sensors
+}
+
+/// Selects the sensors whose label matches one of the given labels, or the
+/// sensor of the CPU package if none are given.
+pub fn select(sensors: Vec<Sensor>, labels: &[String]) -> Vec<Sensor> {
+    if !labels.is_empty() {
+        return labels
+            .iter()
+            .filter_map(|l| sensors.iter().find(|s| s.label.eq_ignore_ascii_case(l)))
+            .cloned()
+            .collect();
+    }
+
+    PACKAGE_SENSORS
+        .iter()
+        .find_map(|p| sensors.iter().find(|s| s.label.eq_ignore_ascii_case(p)))
+        .or(sensors.first())
+        .cloned()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sensors() {
+        let root = std::env::temp_dir().join(format!("macchina-sysfs-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+
+        write("sys/class/hwmon/hwmon0/name", "acpitz\n");
+        write("sys/class/hwmon/hwmon0/temp1_input", "27800\n");
+        write("sys/class/hwmon/hwmon1/name", "coretemp\n");
+        write("sys/class/hwmon/hwmon1/temp1_input", "45000\n");
+        write("sys/class/hwmon/hwmon1/temp1_label", "Package id 0\n");
+        write("sys/class/hwmon/hwmon1/temp2_input", "43500\n");
+        write("sys/class/hwmon/hwmon1/temp2_label", "Core 0\n");
+        write("sys/class/hwmon/hwmon1/fan1_input", "1200\n");
+        write("sys/class/thermal/thermal_zone0/type", "x86_pkg_temp\n");
+        write("sys/class/thermal/thermal_zone0/temp", "46000\n");
+
+        let sensors = sensors(&root);
+        let labels: Vec<_> = sensors.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(labels, ["acpitz", "Package id 0", "Core 0", "x86_pkg_temp"]);
+
+        let selected = select(sensors.clone(), &[]);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].label, "Package id 0");
+        assert_eq!(selected[0].celsius, 45.0);
+
+        let selected = select(sensors, &[String::from("core 0"), String::from("acpitz")]);
+        let celsius: Vec<_> = selected.iter().map(|s| s.celsius).collect();
+        assert_eq!(celsius, [43.5, 27.8]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use libmacchina::traits::ReadoutError;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// The amount of bytes received and transmitted by an interface.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Counters {
+    pub rx: u64,
+    pub tx: u64,
+}
+
+impl Counters {
+    /// Returns the rates, in bytes per second, at which the counters went from
+    /// `self` to `after` over the given interval.
+    fn rate(&self, after: &Counters, interval: Duration) -> Counters {
+        let seconds = interval.as_secs_f64().max(f64::EPSILON);
+        let per_second =
+            |before: u64, after: u64| (after.saturating_sub(before) as f64 / seconds) as u64;
+
+        Counters {
+            rx: per_second(self.rx, after.rx),
+            tx: per_second(self.tx, after.tx),
+        }
+    }
+}
+
+/// The throughput of an interface, along with its totals since boot.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Throughput {
+    pub rate: Counters,
+    pub total: Counters,
+}
+
+/// Returns the counters of every interface, sampled twice over the given
+/// interval.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn sample(interval: Duration) -> Result<HashMap<String, Throughput>, ReadoutError> {
+    let read = || {
+        std::fs::read_to_string("/proc/net/dev")
+            .map(|net_dev| parse_net_dev(&net_dev))
+            .map_err(|e| ReadoutError::Other(format!("Failed to read /proc/net/dev: {e}")))
+    };
+
+    let before = read()?;
+    std::thread::sleep(interval);
+    let after = read()?;
+
+    Ok(throughput(&before, after, interval))
+}
+
+/// Returns the counters of every interface, sampled twice over the given
+/// interval.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn sample(_interval: Duration) -> Result<HashMap<String, Throughput>, ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+/// Computes the throughput of the interfaces present in both samples.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+fn throughput(
+    before: &HashMap<String, Counters>,
+    after: HashMap<String, Counters>,
+    interval: Duration,
+) -> HashMap<String, Throughput> {
+    after
+        .into_iter()
+        .filter_map(|(name, total)| {
+            let rate = before.get(&name)?.rate(&total, interval);
+            Some((name, Throughput { rate, total }))
+        })
+        .collect()
+}
+
+/// Parses `/proc/net/dev`, whose lines look like `eth0: <8 receive fields>
+/// <8 transmit fields>` after a two line header.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+fn parse_net_dev(net_dev: &str) -> HashMap<String, Counters> {
+    net_dev
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, fields) = line.split_once(':')?;
+            let fields: Vec<u64> = fields
+                .split_whitespace()
+                .map(|f| f.parse::<u64>().ok())
+                .collect::<Option<_>>()?;
+
+            let counters = Counters {
+                rx: *fields.first()?,
+                tx: *fields.get(8)?,
+            };
+
+            Some((name.trim().to_owned(), counters))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_throughput() {
+        let net_dev = |eth0: (u64, u64)| {
+            format!(
+                "Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo: 56803095    5929    0    0    0     0          0         0 56803095    5929    0    0    0     0       0          0
+  eth0: {}    1645    0    0    0     0          0         0   {}    1763    0    0    0     0       0          0
+",
+                eth0.0, eth0.1
+            )
+        };
+
+        let before = parse_net_dev(&net_dev((19862288, 145350)));
+        assert_eq!(before.len(), 2);
+        assert_eq!(
+            before.get("eth0"),
+            Some(&Counters {
+                rx: 19862288,
+                tx: 145350
+            })
+        );
+
+        let after = parse_net_dev(&net_dev((19862288 + 1048576, 145350 + 40960)));
+        let throughput = throughput(&before, after, Duration::from_millis(500));
+        let eth0 = throughput.get("eth0").unwrap();
+        assert_eq!(
+            eth0.rate,
+            Counters {
+                rx: 2097152,
+                tx: 81920
+            }
+        );
+        assert_eq!(eth0.total.rx, 19862288 + 1048576);
+        assert_eq!(throughput.get("lo").unwrap().rate, Counters::default());
+    }
+}
@@ -0,0 +1,153 @@
+use libmacchina::traits::ReadoutError;
+
+/// A user logged in on a terminal line, as recorded in utmp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Login {
+    pub user: String,
+    /// The terminal line, e.g. `pts/0` or `tty1`.
+    pub line: String,
+    /// The UNIX timestamp at which the user logged in.
+    pub time: i64,
+}
+
+/// Converts a fixed-size, possibly unterminated, C string field.
+#[cfg(any(
+    all(target_os = "linux", target_env = "gnu"),
+    target_os = "macos",
+    target_os = "freebsd"
+))]
+fn field(chars: &[libc::c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect();
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Returns the user processes recorded in utmp.
+#[cfg(any(
+    all(target_os = "linux", target_env = "gnu"),
+    target_os = "macos",
+    target_os = "freebsd"
+))]
+pub fn logins() -> Result<Vec<Login>, ReadoutError> {
+    let mut logins = vec![];
+
+    // SAFETY: the entries returned by getutxent are only read before the next
+    // call, and the database is closed once every entry has been read.
+    unsafe {
+        libc::setutxent();
+
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+
+            let entry = &*entry;
+            if entry.ut_type == libc::USER_PROCESS {
+                logins.push(Login {
+                    user: field(&entry.ut_user),
+                    line: field(&entry.ut_line),
+                    time: entry.ut_tv.tv_sec as i64,
+                });
+            }
+        }
+
+        libc::endutxent();
+    }
+
+    Ok(logins)
+}
+
+/// Returns the user processes recorded in utmp.
+#[cfg(not(any(
+    all(target_os = "linux", target_env = "gnu"),
+    target_os = "macos",
+    target_os = "freebsd"
+)))]
+pub fn logins() -> Result<Vec<Login>, ReadoutError> {
+    Err(ReadoutError::NotImplemented)
+}
+
+/// Returns the names of the logged-in users, sorted and without duplicates.
+pub fn unique_users(logins: &[Login]) -> Vec<String> {
+    let mut users: Vec<String> = logins
+        .iter()
+        .map(|l| l.user.to_owned())
+        .filter(|u| !u.is_empty())
+        .collect();
+
+    users.sort();
+    users.dedup();
+    users
+}
+
+/// Returns whether the current session is over SSH.
+pub fn is_ssh() -> bool {
+    ["SSH_CONNECTION", "SSH_CLIENT", "SSH_TTY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Returns the terminal line of the current session, e.g. `pts/0`, taken from
+/// `SSH_TTY` or the terminal attached to the standard input.
+#[cfg(unix)]
+pub fn tty() -> Option<String> {
+    let path = std::env::var("SSH_TTY").ok().or_else(|| {
+        let mut buffer = [0u8; 256];
+
+        // SAFETY: ttyname_r writes at most as many bytes as the buffer holds.
+        let ret = unsafe {
+            libc::ttyname_r(libc::STDIN_FILENO, buffer.as_mut_ptr().cast(), buffer.len())
+        };
+
+        (ret == 0).then(|| {
+            let len = buffer.iter().position(|b| *b == 0).unwrap_or(buffer.len());
+            String::from_utf8_lossy(&buffer[..len]).into_owned()
+        })
+    })?;
+
+    tty_line(&path)
+}
+
+/// Returns the terminal line of the current session, e.g. `pts/0`, taken from
+/// `SSH_TTY` or the terminal attached to the standard input.
+#[cfg(not(unix))]
+pub fn tty() -> Option<String> {
+    None
+}
+
+/// Strips `/dev/` from the path of a terminal, which is how utmp records it.
+fn tty_line(path: &str) -> Option<String> {
+    let line = path.strip_prefix("/dev/").unwrap_or(path);
+    (!line.is_empty()).then(|| line.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_users() {
+        let login = |user: &str, line: &str| Login {
+            user: user.to_owned(),
+            line: line.to_owned(),
+            time: 0,
+        };
+
+        let logins = [
+            login("root", "tty1"),
+            login("alice", "pts/0"),
+            login("root", "pts/1"),
+            login("", "pts/2"),
+        ];
+        assert_eq!(unique_users(&logins), ["alice", "root"]);
+
+        assert_eq!(tty_line("/dev/pts/3"), Some(String::from("pts/3")));
+        assert_eq!(tty_line("tty2"), Some(String::from("tty2")));
+        assert_eq!(tty_line("/dev/"), None);
+    }
+}
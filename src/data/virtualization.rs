@@ -0,0 +1,188 @@
+use std::fs;
+use std::path::Path;
+
+/// Substrings of the DMI vendor and product strings, and the hypervisor they
+/// identify, in order of precedence.
+const DMI_HYPERVISORS: &[(&str, &str)] = &[
+    ("KVM", "KVM"),
+    ("QEMU", "QEMU"),
+    ("VMware", "VMware"),
+    ("VirtualBox", "VirtualBox"),
+    ("innotek", "VirtualBox"),
+    ("Virtual Machine", "Hyper-V"),
+    ("Xen", "Xen"),
+    ("Parallels", "Parallels"),
+    ("bhyve", "bhyve"),
+    ("Amazon EC2", "Amazon EC2"),
+    ("Google Compute Engine", "Google Compute Engine"),
+    ("OpenStack", "OpenStack"),
+];
+
+/// Substrings of cgroup paths, and the container runtime they identify.
+const CGROUP_CONTAINERS: &[(&str, &str)] = &[
+    ("libpod", "Podman"),
+    ("docker", "Docker"),
+    ("kubepods", "Kubernetes"),
+    ("lxc", "LXC"),
+    ("machine.slice/machine-", "systemd-nspawn"),
+];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Virtualization {
+    pub hypervisor: Option<String>,
+    pub container: Option<String>,
+}
+
+fn read(root: &Path, path: &str) -> Option<String> {
+    fs::read_to_string(root.join(path))
+        .ok()
+        .map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+}
+
+/// Names the container runtime behind a value of the `container` environment
+/// variable, which systemd and most runtimes set.
+fn container_name(value: &str) -> String {
+    match value {
+        "docker" => String::from("Docker"),
+        "podman" | "oci" => String::from("Podman"),
+        "lxc" | "lxc-libvirt" => String::from("LXC"),
+        "systemd-nspawn" => String::from("systemd-nspawn"),
+        "wsl" => String::from("WSL"),
+        other => other.to_owned(),
+    }
+}
+
+fn hypervisor(root: &Path) -> Option<String> {
+    let osrelease = read(root, "proc/sys/kernel/osrelease").unwrap_or_default();
+    if osrelease.to_lowercase().contains("microsoft") {
+        return Some(String::from("WSL"));
+    }
+
+    let dmi: Vec<String> = ["sys_vendor", "product_name", "bios_vendor"]
+        .iter()
+        .filter_map(|field| read(root, &format!("sys/class/dmi/id/{field}")))
+        .collect();
+
+    let from_dmi = DMI_HYPERVISORS
+        .iter()
+        .find(|(needle, _)| dmi.iter().any(|s| s.contains(needle)))
+        .map(|(_, name)| name.to_string());
+
+    from_dmi
+        .or_else(|| match read(root, "sys/hypervisor/type").as_deref() {
+            Some("xen") => Some(String::from("Xen")),
+            Some(other) => Some(other.to_owned()),
+            None => None,
+        })
+        .or_else(|| {
+            // The flag tells that there is a hypervisor, but not which one.
+            let cpuinfo = read(root, "proc/cpuinfo")?;
+            cpuinfo
+                .lines()
+                .filter(|line| line.starts_with("flags"))
+                .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"))
+                .then(|| String::from("Virtual machine"))
+        })
+}
+
+fn container(root: &Path, container_env: Option<&str>) -> Option<String> {
+    // Reading the environment of PID 1 usually requires elevated privileges.
+    let pid1_env = fs::read(root.join("proc/1/environ"))
+        .ok()
+        .and_then(|environ| {
+            environ
+                .split(|b| *b == 0)
+                .find_map(|var| var.strip_prefix(b"container="))
+                .map(|value| String::from_utf8_lossy(value).into_owned())
+        });
+
+    if let Some(value) = container_env.map(str::to_owned).or(pid1_env) {
+        return Some(container_name(&value));
+    }
+
+    if root.join(".dockerenv").exists() {
+        return Some(String::from("Docker"));
+    }
+
+    if root.join("run/.containerenv").exists() {
+        return Some(String::from("Podman"));
+    }
+
+    if let Some(value) = read(root, "run/systemd/container") {
+        return Some(container_name(&value));
+    }
+
+    let cgroup = read(root, "proc/1/cgroup").unwrap_or_default();
+    cgroup
+        .lines()
+        .filter_map(|line| line.splitn(3, ':').nth(2))
+        .find_map(|path| {
+            CGROUP_CONTAINERS
+                .iter()
+                .find(|(needle, _)| path.contains(needle))
+                .map(|(_, name)| name.to_string())
+        })
+}
+
+/// Detects the hypervisor and the container runtime from the files found under
+/// the given root, which is `/` outside of tests, along with the value of the
+/// `container` environment variable.
+pub fn detect(root: &Path, container_env: Option<&str>) -> Virtualization {
+    Virtualization {
+        hypervisor: hypervisor(root),
+        container: container(root, container_env),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let root = std::env::temp_dir().join(format!("macchina-virt-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+
+        assert_eq!(detect(&root, None), Virtualization::default());
+
+        write(
+            "proc/cpuinfo",
+            "processor\t: 0\nflags\t\t: fpu vme hypervisor\n",
+        );
+        write("proc/1/cgroup", "0::/\n");
+        assert_eq!(
+            detect(&root, None).hypervisor.as_deref(),
+            Some("Virtual machine")
+        );
+
+        write("sys/class/dmi/id/sys_vendor", "QEMU\n");
+        write(
+            "sys/class/dmi/id/product_name",
+            "Standard PC (Q35 + ICH9, 2009)\n",
+        );
+        assert_eq!(detect(&root, None).hypervisor.as_deref(), Some("QEMU"));
+
+        write("proc/1/cgroup", "0::/lxc.payload.web/init.scope\n");
+        assert_eq!(detect(&root, None).container.as_deref(), Some("LXC"));
+
+        write(".dockerenv", "");
+        assert_eq!(detect(&root, None).container.as_deref(), Some("Docker"));
+        assert_eq!(
+            detect(&root, Some("podman")).container.as_deref(),
+            Some("Podman")
+        );
+
+        write(
+            "proc/sys/kernel/osrelease",
+            "5.15.90.1-microsoft-standard-WSL2\n",
+        );
+        assert_eq!(detect(&root, None).hypervisor.as_deref(), Some("WSL"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,6 +1,8 @@
-use crate::data::Readout;
+use crate::cli::Opt;
+use crate::data::{network, Readout, ReadoutKey};
 use colored::Colorize;
 use libmacchina::traits::ReadoutError;
+use std::path::Path;
 
 #[cfg(windows)]
 fn activate_virtual_terminal() {
@@ -63,7 +65,25 @@ fn print_warnings<'a>(warn_items: &[&'a Readout<'a>], total_failed_items: usize)
     }
 }
 
-pub(crate) fn print_doctor(data: &[Readout]) {
+fn print_local_ip_interface(opt: &Opt) {
+    let configured = opt.interface.as_deref().unwrap_or_default();
+
+    match network::local_ip_interface(configured, Path::new("/")) {
+        (Some(interface), reason) => println!(
+            "\nReadout \"{}\" uses interface \"{}\" because {}.",
+            ReadoutKey::LocalIP.to_string().bright_blue(),
+            interface.bright_green(),
+            reason
+        ),
+        (None, reason) => println!(
+            "\nReadout \"{}\" leaves the choice of interface to the system because {}.",
+            ReadoutKey::LocalIP.to_string().bright_blue(),
+            reason
+        ),
+    }
+}
+
+pub(crate) fn print_doctor(data: &[Readout], opt: &Opt) {
     let failed_items: Vec<_> = data.iter().filter(|p| p.1.is_err()).collect();
     let (err_items, warn_items) = split_failed_items(&failed_items);
 
@@ -83,4 +103,8 @@ pub(crate) fn print_doctor(data: &[Readout]) {
 
     print_errors(&err_items);
     print_warnings(&warn_items, failed_items.len());
+
+    if data.iter().any(|r| r.0 == ReadoutKey::LocalIP) {
+        print_local_ip_interface(opt);
+    }
 }
@@ -12,6 +12,15 @@ pub enum Error {
 
     #[error("Failed to parse TOML file {0}")]
     Parsing(#[from] toml::de::Error),
+
+    #[error("Failed to read image {0}")]
+    Image(#[from] image::ImageError),
+
+    #[error("Failed to read palette {0}")]
+    Palette(String),
+
+    #[error("Failed to select ASCII art: {0}")]
+    Ascii(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -27,5 +36,14 @@ pub fn print_errors(err: Error) {
         Error::IO(err) => {
             println!("{}: {:?}", "Error".bright_red(), err);
         }
+        Error::Image(err) => {
+            println!("{}: {}", "Error".bright_red(), err);
+        }
+        Error::Palette(err) => {
+            println!("{}: {}", "Error".bright_red(), err);
+        }
+        Error::Ascii(err) => {
+            println!("{}: {}", "Error".bright_red(), err);
+        }
     }
 }
@@ -1,6 +1,8 @@
+use crate::data::temperature::TemperatureUnit;
+use crate::data::throughput::Counters;
 use bytesize::ByteSize;
 use libmacchina::traits::{BatteryState, PackageManager, ReadoutError};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// This function should return a new `String` constructed from the value \
 /// returned by `traits::GeneralReadout::uptime()`
@@ -107,6 +109,13 @@ pub fn memory(total: u64, used: u64, percentage: bool) -> String {
     output
 }
 
+pub fn temperature(celsius: f64, unit: TemperatureUnit) -> String {
+    match unit {
+        TemperatureUnit::Celsius => format!("{celsius:.1}°C"),
+        TemperatureUnit::Fahrenheit => format!("{:.1}°F", celsius * 9.0 / 5.0 + 32.0),
+    }
+}
+
 /// This function should return a new `String` constructed from the value \
 /// returned by `traits::GeneralReadout::cpu_model_name()`
 pub fn cpu_only(model_name: &str) -> String {
@@ -156,3 +165,157 @@ pub fn disk_space(path: PathBuf, used: u64, total: u64, percentage: bool) -> Str
 
     output
 }
+
+/// Formats an interface as `eth0: 192.168.1.2, 2001:db8::2 (details)`.
+pub fn network_interface(name: &str, addresses: &[String], details: &[String]) -> String {
+    let mut output = String::from(name);
+
+    if !addresses.is_empty() {
+        output.push_str(&format!(": {}", addresses.join(", ")));
+    }
+
+    if !details.is_empty() {
+        output.push_str(&format!(" ({})", details.join(", ")));
+    }
+
+    output
+}
+
+/// Formats the rates of an interface as `eth0: ↓ 1.2 MiB/s ↑ 80.0 KiB/s`,
+/// followed by its totals since boot if any are given.
+pub fn throughput(name: &str, rate: Counters, total: Option<Counters>) -> String {
+    let mut output = format!(
+        "{name}: ↓ {}/s ↑ {}/s",
+        ByteSize::b(rate.rx).to_string_as(true),
+        ByteSize::b(rate.tx).to_string_as(true)
+    );
+
+    if let Some(total) = total {
+        output.push_str(&format!(
+            " (↓ {} ↑ {} since boot)",
+            ByteSize::b(total.rx).to_string_as(true),
+            ByteSize::b(total.tx).to_string_as(true)
+        ));
+    }
+
+    output
+}
+
+/// Formats an init system as `OpenRC (42 running, 1 failed)`, leaving out the
+/// counts that aren't known.
+pub fn init(name: &str, running: Option<usize>, failed: Option<usize>) -> String {
+    let counts: Vec<String> = [(running, "running"), (failed, "failed")]
+        .into_iter()
+        .filter_map(|(count, state)| Some(format!("{} {state}", count?)))
+        .collect();
+
+    if counts.is_empty() {
+        return name.to_owned();
+    }
+
+    format!("{name} ({})", counts.join(", "))
+}
+
+/// Formats a container running on a hypervisor as `Docker on KVM`.
+pub fn virtualization(hypervisor: Option<&str>, container: Option<&str>) -> String {
+    match (hypervisor, container) {
+        (Some(hypervisor), Some(container)) => format!("{container} on {hypervisor}"),
+        (Some(name), None) | (None, Some(name)) => name.to_owned(),
+        (None, None) => String::from("None"),
+    }
+}
+
+/// Formats the logged-in users as `2 (alice, root)`.
+pub fn users(names: &[String]) -> String {
+    format!("{} ({})", names.len(), names.join(", "))
+}
+
+/// Formats a session as `SSH (pts/0, since 2024-05-04 09:12)`, leaving out
+/// what isn't known.
+pub fn session(kind: &str, tty: Option<&str>, since: Option<&str>) -> String {
+    let details: Vec<String> = [tty.map(str::to_owned), since.map(|s| format!("since {s}"))]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if details.is_empty() {
+        return kind.to_owned();
+    }
+
+    format!("{kind} ({})", details.join(", "))
+}
+
+/// Masks every character of the given value, except for the first and last
+/// ones if `keep_edges` is set.
+pub fn redact(value: &str, keep_edges: bool) -> String {
+    let len = value.chars().count();
+
+    value
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if keep_edges && len > 2 && (i == 0 || i + 1 == len) {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Masks the name of the home directory found in the given path, e.g.
+/// `/home/user/data` becomes `/home/****/data`.
+pub fn redact_home(path: &Path, keep_edges: bool) -> PathBuf {
+    let Some(home) = dirs::home_dir() else {
+        return path.to_path_buf();
+    };
+
+    match (path.strip_prefix(&home), home.parent(), home.file_name()) {
+        (Ok(rest), Some(parent), Some(name)) => parent
+            .join(redact(&name.to_string_lossy(), keep_edges))
+            .join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        // usernames and hostnames
+        assert_eq!(redact("user", false), "****");
+        assert_eq!(redact("user", true), "u**r");
+        assert_eq!(redact("thinkpad", true), "t******d");
+        assert_eq!(redact("vm", true), "**");
+        assert_eq!(redact("", true), "");
+
+        // addresses
+        assert_eq!(redact("192.168.1.20", false), "************");
+        assert_eq!(redact("192.168.1.20", true), "1**********0");
+        assert_eq!(redact("fe80::1c2b:3d", true), "f***********d");
+        assert_eq!(redact("ünïcode", true), "ü*****e");
+    }
+
+    #[test]
+    fn test_redact_home() {
+        let Some(home) = dirs::home_dir() else {
+            return;
+        };
+        let Some(name) = home.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+            return;
+        };
+        let parent = home.parent().unwrap();
+
+        assert_eq!(
+            redact_home(&home.join("data"), false),
+            parent.join("*".repeat(name.chars().count())).join("data")
+        );
+        assert_eq!(
+            redact_home(&home, false),
+            parent.join("*".repeat(name.chars().count()))
+        );
+        assert_eq!(redact_home(Path::new("/"), false), Path::new("/"));
+    }
+}
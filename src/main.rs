@@ -10,12 +10,14 @@ mod doctor;
 mod error;
 mod extra;
 mod format;
+mod replace;
 pub mod theme;
 pub mod widgets;
 
 use cli::{Opt, PKG_NAME};
 use error::Result;
 use ratatui::{backend::Backend, buffer::Buffer, layout::Rect};
+use widgets::readout::ReadoutSection;
 
 #[macro_use]
 extern crate lazy_static;
@@ -33,49 +35,106 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if opt.list_ascii {
+        ascii::list_ascii();
+        return Ok(());
+    }
+
     if opt.list_themes {
         theme::list_themes(&opt);
         return Ok(());
     }
 
     let theme = theme::create_theme(&opt);
-    let should_display = data::should_display(&opt);
-    let readout_data = data::get_all_readouts(&opt, &theme, &should_display);
+    let readout_sections: Vec<ReadoutSection> = data::get_sections(&opt)
+        .into_iter()
+        .map(|s| ReadoutSection::new(s.title, data::get_all_readouts(&opt, &theme, &s.show)))
+        .collect();
+    let readout_count: usize = readout_sections.iter().map(|s| s.items().len()).sum();
 
     if opt.doctor {
-        doctor::print_doctor(&readout_data);
+        let readout_data: Vec<_> = readout_sections
+            .iter()
+            .flat_map(|s| s.items())
+            .cloned()
+            .collect();
+        doctor::print_doctor(&readout_data, &opt);
         return Ok(());
     }
 
-    const MAX_ASCII_HEIGHT: usize = 50;
     const MINIMUM_READOUTS_TO_PREFER_SMALL_ASCII: usize = 8;
     let mut backend = buffer::create_backend();
     let mut tmp_buffer = Buffer::empty(Rect::new(0, 0, 500, 50));
     let mut ascii_area = Rect::new(0, 1, 0, tmp_buffer.area.height - 1);
     let prefers_small_ascii =
-        readout_data.len() < MINIMUM_READOUTS_TO_PREFER_SMALL_ASCII || theme.prefers_small_ascii();
-
-    if theme.is_ascii_visible() {
-        if let Some(path) = theme.get_custom_ascii().get_path() {
-            let expanded = shellexpand::tilde(&path.to_string_lossy()).to_string();
-            let file_path = std::path::PathBuf::from(expanded);
-            let ascii_art = if let Some(color) = theme.get_custom_ascii().get_color() {
-                ascii::get_ascii_from_file_override_color(&file_path, color)?
-            } else {
-                ascii::get_ascii_from_file(&file_path)?
-            };
+        readout_count < MINIMUM_READOUTS_TO_PREFER_SMALL_ASCII || theme.prefers_small_ascii();
 
-            if ascii_art.width() != 0 && ascii_art.height() < MAX_ASCII_HEIGHT {
-                ascii_area = buffer::draw_ascii(ascii_art, &mut tmp_buffer);
+    let term_size = buffer::get_terminal_size(&backend);
+    let stacked = term_size.is_some_and(|size| theme.stacks_ascii(size));
+
+    if theme.is_ascii_visible() && term_size.is_none_or(|size| theme.fits_ascii(size)) {
+        let custom_ascii = theme.get_custom_ascii();
+        let path = opt.ascii_path.as_ref().or(custom_ascii.get_path());
+
+        let custom_ascii_art = match (path, custom_ascii.get_image()) {
+            (Some(path), _) if path.as_os_str() != "-" => {
+                let expanded = shellexpand::tilde(&path.to_string_lossy()).to_string();
+                let file_path = std::path::PathBuf::from(expanded);
+                let buffer = ascii::read_ascii_file(&file_path)?;
+                Some(ascii::ascii_to_text(&buffer, custom_ascii))
             }
-        } else if prefers_small_ascii {
-            // prefer smaller ascii in this case
-            if let Some(ascii) = ascii::select_ascii(ascii::AsciiSize::Small) {
-                ascii_area = buffer::draw_ascii(ascii, &mut tmp_buffer);
+            (None, Some(image)) => {
+                let expanded = shellexpand::tilde(&image.to_string_lossy()).to_string();
+                let file_path = std::path::PathBuf::from(expanded);
+                ascii::get_ascii_from_image(&file_path, custom_ascii).ok()
             }
+            // art converted from an image, read from stdin or generated by a
+            // command falls back to the built-in one if it can't be obtained.
+            _ => {
+                let buffer = match path {
+                    Some(_) => ascii::read_ascii_stdin().ok(),
+                    None => custom_ascii.get_command().and_then(|command| {
+                        ascii::read_ascii_command(command, custom_ascii.get_command_timeout()).ok()
+                    }),
+                };
+
+                buffer.map(|buffer| ascii::ascii_to_text(&buffer, custom_ascii))
+            }
+        };
+
+        // empty art, or art that's too tall to be displayed, is replaced with
+        // the built-in one as well.
+        let custom_ascii_art = custom_ascii_art
+            .filter(|art| art.width() != 0 && art.height() < ascii::MAX_ASCII_HEIGHT);
+
+        if let Some(ascii_art) = custom_ascii_art {
+            ascii_area = buffer::draw_ascii(ascii_art, &mut tmp_buffer);
         } else {
-            // prefer bigger ascii otherwise
-            if let Some(ascii) = ascii::select_ascii(ascii::AsciiSize::Big) {
+            if let Err(e) = ascii::check_selection(&opt.ascii) {
+                error::print_errors(e);
+            }
+
+            // prefer bigger ascii, unless there's only a few readouts or it
+            // doesn't fit in the terminal.
+            let big_ascii = if prefers_small_ascii {
+                None
+            } else {
+                let readout_size = term_size.map(|_| {
+                    buffer::measure_readout_data(readout_sections.clone(), theme.clone(), &opt)
+                });
+
+                ascii::select_ascii(ascii::AsciiSize::Big, &opt.ascii).filter(|art| {
+                    term_size
+                        .zip(readout_size)
+                        .is_none_or(|(term_size, readout_size)| {
+                            buffer::ascii_fits(art, readout_size, term_size, stacked)
+                        })
+                })
+            };
+
+            if let Some(ascii) =
+                big_ascii.or_else(|| ascii::select_ascii(ascii::AsciiSize::Small, &opt.ascii))
+            {
                 ascii_area = buffer::draw_ascii(ascii, &mut tmp_buffer);
             }
         }
@@ -83,17 +142,25 @@ fn main() -> Result<()> {
 
     let tmp_buffer_area = tmp_buffer.area;
 
-    buffer::draw_readout_data(
-        readout_data,
-        theme,
-        &mut tmp_buffer,
+    let readout_area = if stacked && ascii_area.width != 0 {
+        // the readouts are displayed below the ascii on narrow terminals.
+        let y = ascii_area.y + ascii_area.height + 1;
+        Rect::new(
+            1,
+            y,
+            tmp_buffer_area.width - 2,
+            tmp_buffer_area.height.saturating_sub(y),
+        )
+    } else {
         Rect::new(
             ascii_area.x + ascii_area.width + 2,
             ascii_area.y,
-            tmp_buffer_area.width - ascii_area.width - 4,
+            tmp_buffer_area.width.saturating_sub(ascii_area.width + 4),
             ascii_area.height,
-        ),
-    );
+        )
+    };
+
+    buffer::draw_readout_data(readout_sections, theme, &opt, &mut tmp_buffer, readout_area);
 
     buffer::write_buffer_to_console(&mut backend, &mut tmp_buffer)?;
 
@@ -0,0 +1,209 @@
+use crate::data::{Readout, ReadoutKey};
+use ratatui::text::{Line, Span};
+use regex::Regex;
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::borrow::Cow;
+
+/// A regular expression, which is compiled as soon as the configuration file
+/// is parsed so that an invalid pattern is reported as a configuration error.
+#[derive(Debug, Clone)]
+pub struct Pattern(Regex);
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D>(deserializer: D) -> Result<Pattern, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Regex::new(&s).map(Pattern).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+/// A replacement rule, as defined by a `[[replace]]` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReplaceRule {
+    /// The readout to apply the rule to, or every readout if unspecified.
+    pub readout: Option<ReadoutKey>,
+    pub pattern: Pattern,
+    #[serde(default)]
+    pub with: String,
+}
+
+impl ReplaceRule {
+    fn new(readout: Option<ReadoutKey>, pattern: &str, with: &str) -> ReplaceRule {
+        ReplaceRule {
+            readout,
+            pattern: Pattern(Regex::new(pattern).unwrap()),
+            with: with.to_string(),
+        }
+    }
+
+    fn applies_to(&self, readout_key: ReadoutKey) -> bool {
+        self.readout.is_none_or(|r| r == readout_key)
+    }
+}
+
+lazy_static! {
+    /// Built-in rules which remove the noise commonly found in readouts.
+    static ref TIDY_RULES: Vec<ReplaceRule> = vec![
+        ReplaceRule::new(Some(ReadoutKey::Processor), r"\s*CPU\s*@\s*[\d.]+\s*[GM]Hz", ""),
+        ReplaceRule::new(Some(ReadoutKey::Processor), r"\s+with Radeon( \w+)? Graphics", ""),
+        ReplaceRule::new(Some(ReadoutKey::Processor), r"\s+\d+-Core Processor", ""),
+        ReplaceRule::new(
+            Some(ReadoutKey::GPU),
+            r"\s*\b(Corporation|Technologies|Inc\.|Co\., Ltd\.)",
+            "",
+        ),
+        ReplaceRule::new(Some(ReadoutKey::Processor), r"\s{2,}", " "),
+        ReplaceRule::new(Some(ReadoutKey::GPU), r"\s{2,}", " "),
+    ];
+}
+
+/// Applies a rule to the text of a line. Matches that lie within a single span
+/// keep its style, whereas a match crossing spans collapses the line into a
+/// single span, styled like the first one.
+fn replace_line(line: &mut Line, rule: &ReplaceRule) {
+    let mut boundaries = vec![];
+    let mut content = String::new();
+    for span in &line.spans {
+        content.push_str(&span.content);
+        boundaries.push(content.len());
+    }
+
+    let crosses_spans = rule
+        .pattern
+        .0
+        .find_iter(&content)
+        .any(|m| boundaries.iter().any(|b| m.start() < *b && *b < m.end()));
+
+    if crosses_spans {
+        let replaced = rule
+            .pattern
+            .0
+            .replace_all(&content, &rule.with)
+            .into_owned();
+        let style = line.spans.first().map(|s| s.style).unwrap_or_default();
+        line.spans = vec![Span::styled(replaced, style)];
+        return;
+    }
+
+    for span in line.spans.iter_mut() {
+        if let Cow::Owned(s) = rule.pattern.0.replace_all(&span.content, &rule.with) {
+            span.content = Cow::Owned(s);
+        }
+    }
+}
+
+/// Applies the built-in rules if `tidy` is set, followed by the given rules,
+/// in order, to the values of the given readouts.
+pub fn apply(readouts: &mut [Readout], rules: &[ReplaceRule], tidy: bool) {
+    let tidy_rules: &[ReplaceRule] = if tidy { &TIDY_RULES } else { &[] };
+
+    for Readout(key, value) in readouts.iter_mut() {
+        let Ok(text) = value else {
+            continue;
+        };
+
+        for rule in tidy_rules
+            .iter()
+            .chain(rules)
+            .filter(|r| r.applies_to(*key))
+        {
+            for line in text.lines.iter_mut() {
+                replace_line(line, rule);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tidy_rules() {
+        let mut readouts = vec![
+            Readout::new(
+                ReadoutKey::Processor,
+                "Intel® Core™ i7-6600U CPU @ 2.60GHz (4)",
+            ),
+            Readout::new(
+                ReadoutKey::Processor,
+                "AMD Ryzen 7 5800H with Radeon Graphics (16)",
+            ),
+            Readout::new(
+                ReadoutKey::GPU,
+                "NVIDIA Corporation GA104 [GeForce RTX 3070]",
+            ),
+        ];
+
+        apply(&mut readouts, &[], true);
+
+        let values: Vec<String> = readouts
+            .iter()
+            .map(|r| r.1.as_ref().unwrap().to_string())
+            .collect();
+
+        assert_eq!(
+            values,
+            [
+                "Intel® Core™ i7-6600U (4)",
+                "AMD Ryzen 7 5800H (16)",
+                "NVIDIA GA104 [GeForce RTX 3070]"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tidy_rules_keep_other_readouts() {
+        use ratatui::style::{Color, Style};
+
+        let bar = Line::from(vec![
+            Span::raw("[ "),
+            Span::styled("• • •  ", Style::default().fg(Color::Blue)),
+            Span::raw("    ]"),
+        ]);
+        let mut readouts = vec![Readout::new(ReadoutKey::Memory, bar.clone())];
+
+        apply(&mut readouts, &[], true);
+
+        let text = readouts[0].1.as_ref().unwrap();
+        assert_eq!(text.lines[0], bar);
+    }
+
+    #[test]
+    fn test_rule_across_spans() {
+        use ratatui::style::{Color, Style};
+
+        let red = Style::default().fg(Color::Red);
+        let line = || Line::from(vec![Span::styled("root", red), Span::raw("@vm")]);
+
+        let mut readouts = vec![Readout::new(ReadoutKey::Host, line())];
+        apply(
+            &mut readouts,
+            &[ReplaceRule::new(None, "t@v", "t at v")],
+            false,
+        );
+        let text = readouts[0].1.as_ref().unwrap();
+        assert_eq!(text.lines[0].spans, [Span::styled("root at vm", red)]);
+
+        let mut readouts = vec![Readout::new(ReadoutKey::Host, line())];
+        apply(&mut readouts, &[ReplaceRule::new(None, "vm", "box")], false);
+        let text = readouts[0].1.as_ref().unwrap();
+        assert_eq!(
+            text.lines[0].spans,
+            [Span::styled("root", red), Span::raw("@box")]
+        );
+    }
+}
@@ -3,10 +3,13 @@ use crate::data::ReadoutKey;
 use crate::error;
 use crate::extra;
 use crate::theme::components::*;
+use crate::theme::modifiers::{to_modifier, TextModifier};
+use crate::theme::scheme;
 use crate::Result;
 use colored::Colorize;
 use dirs;
-use ratatui::style::Color;
+use ratatui::layout::Size;
+use ratatui::style::{Color, Style};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -17,6 +20,8 @@ pub struct Theme {
     custom_ascii: ASCII,
     bar: Bar,
     r#box: Block,
+    sections: Sections,
+    header: Header,
     separator: String,
     randomize: Randomize,
     spacing: usize,
@@ -24,9 +29,22 @@ pub struct Theme {
     palette: Palette,
     hide_ascii: bool,
     prefer_small_ascii: bool,
+    min_width_for_ascii: Option<u16>,
+    min_height_for_ascii: Option<u16>,
+    stack_ascii_below: Option<u16>,
     keys: Keys,
+    styles: Styles,
     key_color: Color,
     separator_color: Color,
+    value_color: Option<Color>,
+    key_modifiers: Vec<TextModifier>,
+    separator_modifiers: Vec<TextModifier>,
+    value_modifiers: Vec<TextModifier>,
+    on_error: OnError,
+    error_placeholder: String,
+    error_color: Color,
+    wal_colors: Option<PathBuf>,
+    base16_scheme: Option<PathBuf>,
     #[serde(skip_serializing, skip_deserializing)]
     name: String,
     #[serde(skip_serializing, skip_deserializing)]
@@ -40,18 +58,33 @@ impl Default for Theme {
         Theme {
             key_color: Color::Blue,
             separator_color: Color::Yellow,
+            value_color: None,
+            key_modifiers: vec![],
+            separator_modifiers: vec![],
+            value_modifiers: vec![],
+            on_error: OnError::default(),
+            error_placeholder: String::from("N/A"),
+            error_color: Color::Yellow,
+            wal_colors: None,
+            base16_scheme: None,
             separator: String::from("-"),
             palette: Palette::default(),
             randomize: Randomize::default(),
             custom_ascii: ASCII::default(),
             bar: Bar::default(),
             r#box: Block::default(),
+            sections: Sections::default(),
+            header: Header::default(),
             keys: Keys::default(),
+            styles: Styles::default(),
             name: String::new(),
             filepath: PathBuf::new(),
             active: false,
             hide_ascii: false,
             prefer_small_ascii: false,
+            min_width_for_ascii: None,
+            min_height_for_ascii: None,
+            stack_ascii_below: None,
             spacing: 2,
             padding: 2,
         }
@@ -65,15 +98,30 @@ impl Theme {
             key_color: custom.key_color,
             separator: custom.separator,
             separator_color: custom.separator_color,
+            value_color: custom.value_color,
+            key_modifiers: custom.key_modifiers,
+            separator_modifiers: custom.separator_modifiers,
+            value_modifiers: custom.value_modifiers,
+            on_error: custom.on_error,
+            error_placeholder: custom.error_placeholder,
+            error_color: custom.error_color,
+            wal_colors: custom.wal_colors,
+            base16_scheme: custom.base16_scheme,
             spacing: custom.spacing,
             padding: custom.padding,
             palette: custom.palette,
             hide_ascii: custom.hide_ascii,
             prefer_small_ascii: custom.prefer_small_ascii,
+            min_width_for_ascii: custom.min_width_for_ascii,
+            min_height_for_ascii: custom.min_height_for_ascii,
+            stack_ascii_below: custom.stack_ascii_below,
             r#box: custom.r#box,
+            sections: custom.sections,
+            header: custom.header,
             custom_ascii: custom.custom_ascii,
             randomize: custom.randomize,
             keys: custom.keys,
+            styles: custom.styles,
             name: custom.name,
             filepath: custom.filepath,
             active: custom.active,
@@ -104,6 +152,10 @@ impl Theme {
         &self.keys
     }
 
+    pub fn get_styles(&self) -> &Styles {
+        &self.styles
+    }
+
     pub fn get_randomization(&self) -> &Randomize {
         &self.randomize
     }
@@ -120,6 +172,14 @@ impl Theme {
         &self.r#box
     }
 
+    pub fn get_sections(&self) -> &Sections {
+        &self.sections
+    }
+
+    pub fn get_header(&self) -> &Header {
+        &self.header
+    }
+
     pub fn get_separator(&self) -> &str {
         &self.separator
     }
@@ -132,10 +192,41 @@ impl Theme {
         self.separator_color
     }
 
+    pub fn get_value_color(&self) -> Option<Color> {
+        self.value_color
+    }
+
+    pub fn get_on_error(&self) -> OnError {
+        self.on_error
+    }
+
+    pub fn get_error_placeholder(&self) -> &str {
+        &self.error_placeholder
+    }
+
+    pub fn get_error_color(&self) -> Color {
+        self.error_color
+    }
+
     pub fn prefers_small_ascii(&self) -> bool {
         self.prefer_small_ascii
     }
 
+    /// Whether the terminal is large enough for the ASCII art to be displayed.
+    pub fn fits_ascii(&self, term_size: Size) -> bool {
+        self.min_width_for_ascii
+            .is_none_or(|w| term_size.width >= w)
+            && self
+                .min_height_for_ascii
+                .is_none_or(|h| term_size.height >= h)
+    }
+
+    /// Whether the terminal is narrow enough for the ASCII art to be displayed
+    /// above the readouts rather than next to them.
+    pub fn stacks_ascii(&self, term_size: Size) -> bool {
+        self.stack_ascii_below.is_some_and(|w| term_size.width < w)
+    }
+
     pub fn get_padding(&self) -> usize {
         self.padding
     }
@@ -198,26 +289,126 @@ impl Theme {
         match *readout_key {
             ReadoutKey::Host => self.keys.get_host(),
             ReadoutKey::Kernel => self.keys.get_kernel(),
+            ReadoutKey::Init => self.keys.get_init(),
             ReadoutKey::OperatingSystem => self.keys.get_os(),
             ReadoutKey::Machine => self.keys.get_machine(),
+            ReadoutKey::Virtualization => self.keys.get_virtualization(),
             ReadoutKey::Distribution => self.keys.get_distro(),
             ReadoutKey::LocalIP => self.keys.get_local_ip(),
+            ReadoutKey::Network => self.keys.get_network(),
+            ReadoutKey::Throughput => self.keys.get_throughput(),
             ReadoutKey::Resolution => self.keys.get_resolution(),
             ReadoutKey::Shell => self.keys.get_shell(),
             ReadoutKey::Terminal => self.keys.get_terminal(),
+            ReadoutKey::Users => self.keys.get_users(),
+            ReadoutKey::Session => self.keys.get_session(),
             ReadoutKey::WindowManager => self.keys.get_wm(),
             ReadoutKey::DesktopEnvironment => self.keys.get_de(),
             ReadoutKey::Packages => self.keys.get_packages(),
             ReadoutKey::Processor => self.keys.get_cpu(),
             ReadoutKey::ProcessorLoad => self.keys.get_cpu_load(),
+            ReadoutKey::LoadAverage => self.keys.get_load_average(),
+            ReadoutKey::Temperature => self.keys.get_temperature(),
             ReadoutKey::Battery => self.keys.get_battery(),
             ReadoutKey::Backlight => self.keys.get_backlight(),
             ReadoutKey::Uptime => self.keys.get_uptime(),
+            ReadoutKey::Locale => self.keys.get_locale(),
+            ReadoutKey::Timezone => self.keys.get_timezone(),
+            ReadoutKey::DateTime => self.keys.get_datetime(),
             ReadoutKey::Memory => self.keys.get_memory(),
+            ReadoutKey::Swap => self.keys.get_swap(),
             ReadoutKey::GPU => self.keys.get_gpu(),
             ReadoutKey::DiskSpace => self.keys.get_disk_space(),
         }
     }
+
+    pub fn style(&self, readout_key: &ReadoutKey) -> Option<&ReadoutStyle> {
+        match *readout_key {
+            ReadoutKey::Host => self.styles.host.as_ref(),
+            ReadoutKey::Kernel => self.styles.kernel.as_ref(),
+            ReadoutKey::Init => self.styles.init.as_ref(),
+            ReadoutKey::OperatingSystem => self.styles.os.as_ref(),
+            ReadoutKey::Machine => self.styles.machine.as_ref(),
+            ReadoutKey::Virtualization => self.styles.virtualization.as_ref(),
+            ReadoutKey::Distribution => self.styles.distro.as_ref(),
+            ReadoutKey::LocalIP => self.styles.local_ip.as_ref(),
+            ReadoutKey::Network => self.styles.network.as_ref(),
+            ReadoutKey::Throughput => self.styles.throughput.as_ref(),
+            ReadoutKey::Resolution => self.styles.resolution.as_ref(),
+            ReadoutKey::Shell => self.styles.shell.as_ref(),
+            ReadoutKey::Terminal => self.styles.terminal.as_ref(),
+            ReadoutKey::Users => self.styles.users.as_ref(),
+            ReadoutKey::Session => self.styles.session.as_ref(),
+            ReadoutKey::WindowManager => self.styles.wm.as_ref(),
+            ReadoutKey::DesktopEnvironment => self.styles.de.as_ref(),
+            ReadoutKey::Packages => self.styles.packages.as_ref(),
+            ReadoutKey::Processor => self.styles.cpu.as_ref(),
+            ReadoutKey::ProcessorLoad => self.styles.cpu_load.as_ref(),
+            ReadoutKey::LoadAverage => self.styles.load_average.as_ref(),
+            ReadoutKey::Temperature => self.styles.temperature.as_ref(),
+            ReadoutKey::Battery => self.styles.battery.as_ref(),
+            ReadoutKey::Backlight => self.styles.backlight.as_ref(),
+            ReadoutKey::Uptime => self.styles.uptime.as_ref(),
+            ReadoutKey::Locale => self.styles.locale.as_ref(),
+            ReadoutKey::Timezone => self.styles.timezone.as_ref(),
+            ReadoutKey::DateTime => self.styles.datetime.as_ref(),
+            ReadoutKey::Memory => self.styles.memory.as_ref(),
+            ReadoutKey::Swap => self.styles.swap.as_ref(),
+            ReadoutKey::GPU => self.styles.gpu.as_ref(),
+            ReadoutKey::DiskSpace => self.styles.disk_space.as_ref(),
+        }
+    }
+
+    /// Returns the color of the given readout's key, taking its overrides into account.
+    pub fn key_color(&self, readout_key: &ReadoutKey) -> Color {
+        self.style(readout_key)
+            .and_then(|s| s.get_key_color())
+            .unwrap_or(self.key_color)
+    }
+
+    /// Returns the style of the given readout's key, taking its overrides into account.
+    pub fn key_style(&self, readout_key: &ReadoutKey) -> Style {
+        let mut modifiers = to_modifier(&self.key_modifiers);
+        if let Some(s) = self.style(readout_key) {
+            modifiers |= s.get_key_modifiers();
+        }
+
+        Style::default()
+            .fg(self.key_color(readout_key))
+            .add_modifier(modifiers)
+    }
+
+    /// Returns the style of the given readout's separator, taking its overrides into account.
+    pub fn separator_style(&self, readout_key: &ReadoutKey) -> Style {
+        let mut color = self.separator_color;
+        let mut modifiers = to_modifier(&self.separator_modifiers);
+        if let Some(s) = self.style(readout_key) {
+            color = s.get_separator_color().unwrap_or(color);
+            modifiers |= s.get_separator_modifiers();
+        }
+
+        Style::default().fg(color).add_modifier(modifiers)
+    }
+
+    /// Returns the style of the given readout's value, taking its overrides into account.
+    pub fn value_style(&self, readout_key: &ReadoutKey) -> Style {
+        let mut color = self.value_color;
+        let mut modifiers = to_modifier(&self.value_modifiers);
+        if let Some(s) = self.style(readout_key) {
+            color = s.get_value_color().or(color);
+            modifiers |= s.get_value_modifiers();
+        }
+
+        let style = Style::default().add_modifier(modifiers);
+        match color {
+            Some(c) => style.fg(c),
+            None => style,
+        }
+    }
+
+    pub fn icon(&self, readout_key: &ReadoutKey) -> Option<&str> {
+        self.style(readout_key).and_then(|s| s.get_icon())
+    }
 }
 
 impl fmt::Display for Theme {
@@ -271,13 +462,22 @@ pub fn get_theme(path: &Path) -> Result<Theme> {
 
     let buffer = fs::read(path)?;
     let contents = str::from_utf8(buffer.as_slice())?;
-    Ok(toml::from_str(contents)?)
+    let table = scheme::parse_theme(contents)?;
+    Ok(Theme::deserialize(table)?)
 }
 
 /// Searches for and returns the specified theme.
 pub fn create_theme(opt: &Opt) -> Theme {
     let locations = locations();
     let mut theme = Theme::default();
+    if let Some(path) = &opt.theme_from_palette {
+        let path = PathBuf::from(shellexpand::tilde(&path.to_string_lossy()).to_string());
+        match scheme::theme_from_palette(&path).and_then(|t| Ok(Theme::deserialize(t)?)) {
+            Ok(t) => return t,
+            Err(e) => error::print_errors(e),
+        }
+    }
+
     if let Some(th) = &opt.theme {
         locations.iter().find(|d| {
             let path = d.join(format!("{th}.toml"));
@@ -353,4 +553,67 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_section_layout() {
+        for layout in ["box", "Box"] {
+            let theme: Theme =
+                toml::from_str(&format!("[sections]\nlayout = \"{layout}\"")).unwrap();
+            assert!(matches!(
+                theme.get_sections().get_layout(),
+                SectionLayout::Box
+            ));
+        }
+
+        for layout in ["heading", "Heading"] {
+            let theme: Theme =
+                toml::from_str(&format!("[sections]\nlayout = \"{layout}\"")).unwrap();
+            assert!(matches!(
+                theme.get_sections().get_layout(),
+                SectionLayout::Heading
+            ));
+        }
+    }
+
+    #[test]
+    fn test_fits_and_stacks_ascii() {
+        let theme: Theme = toml::from_str(
+            r#"
+            min_width_for_ascii = 80
+            min_height_for_ascii = 20
+            stack_ascii_below = 60
+            "#,
+        )
+        .unwrap();
+
+        assert!(theme.fits_ascii(Size::new(80, 20)));
+        assert!(!theme.fits_ascii(Size::new(79, 20)));
+        assert!(!theme.fits_ascii(Size::new(80, 19)));
+
+        assert!(theme.stacks_ascii(Size::new(59, 20)));
+        assert!(!theme.stacks_ascii(Size::new(60, 20)));
+
+        let theme = Theme::default();
+        assert!(theme.fits_ascii(Size::new(1, 1)));
+        assert!(!theme.stacks_ascii(Size::new(1, 1)));
+    }
+
+    #[test]
+    fn test_ascii_fits() {
+        use crate::buffer::ascii_fits;
+        use ratatui::text::Text;
+
+        let ascii = Text::raw(["##########"; 5].join("\n"));
+        let readouts = Size::new(30, 8);
+
+        // next to the readouts, with a gap of 4 cells between them.
+        assert!(ascii_fits(&ascii, readouts, Size::new(44, 6), false));
+        assert!(!ascii_fits(&ascii, readouts, Size::new(43, 6), false));
+        assert!(!ascii_fits(&ascii, readouts, Size::new(44, 5), false));
+
+        // above the readouts, with 2 lines between them.
+        assert!(ascii_fits(&ascii, readouts, Size::new(11, 15), true));
+        assert!(!ascii_fits(&ascii, readouts, Size::new(10, 15), true));
+        assert!(!ascii_fits(&ascii, readouts, Size::new(11, 14), true));
+    }
 }
@@ -1,4 +1,6 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use ratatui::symbols::border;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
 
 #[derive(Debug, Clone)]
 pub enum Border {
@@ -6,6 +8,7 @@ pub enum Border {
     Plain,
     Rounded,
     Double,
+    Custom,
 }
 
 impl<'de> Deserialize<'de> for Border {
@@ -18,6 +21,7 @@ impl<'de> Deserialize<'de> for Border {
             "thick" => Ok(Self::Thick),
             "plain" => Ok(Self::Plain),
             "double" => Ok(Self::Double),
+            "custom" => Ok(Self::Custom),
             _ => Ok(Self::Rounded),
         }
     }
@@ -31,3 +35,135 @@ impl Serialize for Border {
         serializer.serialize_some(&self)
     }
 }
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TitleAlignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl<'de> Deserialize<'de> for TitleAlignment {
+    fn deserialize<D>(deserializer: D) -> Result<TitleAlignment, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match &s.as_str().to_lowercase()[..] {
+            "left" => Ok(Self::Left),
+            "center" | "centre" => Ok(Self::Center),
+            "right" => Ok(Self::Right),
+            _ => Err(de::Error::custom(format!(
+                "unknown title alignment \"{s}\", expected \"left\", \"center\" or \"right\""
+            ))),
+        }
+    }
+}
+
+impl Serialize for TitleAlignment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Left => serializer.serialize_str("left"),
+            Self::Center => serializer.serialize_str("center"),
+            Self::Right => serializer.serialize_str("right"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum TitlePosition {
+    #[default]
+    Top,
+    Bottom,
+}
+
+impl<'de> Deserialize<'de> for TitlePosition {
+    fn deserialize<D>(deserializer: D) -> Result<TitlePosition, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match &s.as_str().to_lowercase()[..] {
+            "top" => Ok(Self::Top),
+            "bottom" => Ok(Self::Bottom),
+            _ => Err(de::Error::custom(format!(
+                "unknown title position \"{s}\", expected \"top\" or \"bottom\""
+            ))),
+        }
+    }
+}
+
+impl Serialize for TitlePosition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Top => serializer.serialize_str("top"),
+            Self::Bottom => serializer.serialize_str("bottom"),
+        }
+    }
+}
+
+/// The eight glyphs making up a custom border, listed clockwise starting from
+/// the top-left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct CustomBorder(pub border::Set);
+
+impl<'de> Deserialize<'de> for CustomBorder {
+    fn deserialize<D>(deserializer: D) -> Result<CustomBorder, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let glyphs: Vec<char> = s.chars().collect();
+        if glyphs.len() != 8 {
+            return Err(de::Error::custom(format!(
+                "custom_border must be made up of exactly 8 characters, found {}",
+                glyphs.len()
+            )));
+        }
+
+        // ratatui requires the symbols to be static, so they're leaked once,
+        // as the theme is parsed.
+        let glyph =
+            |i: usize| -> &'static str { Box::leak(glyphs[i].to_string().into_boxed_str()) };
+
+        Ok(CustomBorder(border::Set {
+            top_left: glyph(0),
+            horizontal_top: glyph(1),
+            top_right: glyph(2),
+            vertical_right: glyph(3),
+            bottom_right: glyph(4),
+            horizontal_bottom: glyph(5),
+            bottom_left: glyph(6),
+            vertical_left: glyph(7),
+        }))
+    }
+}
+
+impl Serialize for CustomBorder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let set = &self.0;
+        serializer.serialize_str(
+            &[
+                set.top_left,
+                set.horizontal_top,
+                set.top_right,
+                set.vertical_right,
+                set.bottom_right,
+                set.horizontal_bottom,
+                set.bottom_left,
+                set.vertical_left,
+            ]
+            .concat(),
+        )
+    }
+}
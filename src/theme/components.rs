@@ -1,10 +1,17 @@
-use crate::theme::borders::Border;
+use crate::theme::borders::{Border, CustomBorder, TitleAlignment, TitlePosition};
 use crate::theme::color::*;
+use crate::theme::modifiers::{to_modifier, TextModifier};
 use rand::Rng;
-use ratatui::style::Color;
+use ratatui::layout::Alignment;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::border;
+use ratatui::text::{Line, Text};
+use ratatui::widgets::block::Position;
 use ratatui::widgets::BorderType;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
+use unicode_width::UnicodeWidthChar;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Palette {
@@ -119,6 +126,17 @@ pub struct ASCII {
 
     #[serde(default)]
     color: Option<Color>,
+
+    #[serde(default)]
+    colors: Vec<Color>,
+
+    command: Option<String>,
+    command_timeout: Option<u64>,
+
+    image_to_text: Option<PathBuf>,
+    image_charset: Option<ImageCharset>,
+    image_width: Option<u16>,
+    image_height: Option<u16>,
 }
 
 impl ASCII {
@@ -126,9 +144,46 @@ impl ASCII {
         self.color
     }
 
+    pub fn get_colors(&self) -> &[Color] {
+        &self.colors
+    }
+
     pub fn get_path(&self) -> Option<&PathBuf> {
         self.path.as_ref()
     }
+
+    pub fn get_image(&self) -> Option<&PathBuf> {
+        self.image_to_text.as_ref()
+    }
+
+    pub fn get_image_charset(&self) -> ImageCharset {
+        self.image_charset.unwrap_or_default()
+    }
+
+    pub fn get_image_width(&self) -> u16 {
+        self.image_width.unwrap_or(32)
+    }
+
+    pub fn get_image_height(&self) -> Option<u16> {
+        self.image_height
+    }
+
+    pub fn get_command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    pub fn get_command_timeout(&self) -> Duration {
+        Duration::from_millis(self.command_timeout.unwrap_or(1000))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageCharset {
+    Ascii,
+    #[default]
+    HalfBlocks,
+    Braille,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,6 +193,134 @@ pub enum PaletteType {
     Full,
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnError {
+    #[default]
+    Hide,
+    Placeholder,
+    Message,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionLayout {
+    #[serde(alias = "Box")]
+    Box,
+    #[serde(alias = "Heading")]
+    Heading,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sections {
+    layout: Option<SectionLayout>,
+    gap: Option<u16>,
+    align_keys: Option<bool>,
+    heading_color: Option<Color>,
+    heading_modifiers: Option<Vec<TextModifier>>,
+}
+
+impl Default for Sections {
+    fn default() -> Self {
+        Sections {
+            layout: Some(SectionLayout::Heading),
+            gap: Some(1),
+            align_keys: None,
+            heading_color: None,
+            heading_modifiers: None,
+        }
+    }
+}
+
+impl Sections {
+    pub fn get_layout(&self) -> SectionLayout {
+        if let Some(l) = self.layout {
+            return l;
+        }
+
+        SectionLayout::Heading
+    }
+
+    pub fn get_gap(&self) -> u16 {
+        if let Some(g) = self.gap {
+            return g;
+        }
+
+        1
+    }
+
+    pub fn align_keys(&self) -> bool {
+        if let Some(a) = self.align_keys {
+            return a;
+        }
+
+        false
+    }
+
+    /// Returns the style of the headings, which falls back to bold text
+    /// colored after the keys.
+    pub fn get_heading_style(&self, key_color: Color) -> Style {
+        let modifiers = match &self.heading_modifiers {
+            Some(m) => to_modifier(m),
+            None => Modifier::BOLD,
+        };
+
+        Style::default()
+            .fg(self.heading_color.unwrap_or(key_color))
+            .add_modifier(modifiers)
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Header {
+    text: Option<String>,
+    color: Option<Color>,
+    modifiers: Option<Vec<TextModifier>>,
+    underline: Option<char>,
+    underline_color: Option<Color>,
+    footer: Option<String>,
+    footer_color: Option<Color>,
+}
+
+impl Header {
+    pub fn get_text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn get_footer(&self) -> Option<&str> {
+        self.footer.as_deref()
+    }
+
+    /// Returns the header line, followed by its underline if one was
+    /// specified, which is as wide as the header itself.
+    pub fn to_text(&self, header: String, key_color: Color) -> Text<'static> {
+        let style = Style::default()
+            .fg(self.color.unwrap_or(key_color))
+            .add_modifier(to_modifier(self.modifiers.as_deref().unwrap_or_default()));
+        let line = Line::styled(header, style);
+        let width = line.width();
+        let mut lines = vec![line];
+
+        if let Some(glyph) = self.underline {
+            let glyph_width = glyph.width().unwrap_or(1).max(1);
+            let underline = glyph.to_string().repeat(width / glyph_width);
+            lines.push(match self.underline_color {
+                Some(c) => Line::styled(underline, Style::default().fg(c)),
+                None => Line::raw(underline),
+            });
+        }
+
+        Text::from(lines)
+    }
+
+    pub fn footer_to_text(&self, footer: String) -> Text<'static> {
+        match self.footer_color {
+            Some(c) => Text::styled(footer, Style::default().fg(c)),
+            None => Text::raw(footer),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InnerMargin {
     x: u16,
@@ -153,18 +336,30 @@ impl Default for InnerMargin {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Block {
     title: Option<String>,
+    title_color: Option<Color>,
+    title_modifiers: Option<Vec<TextModifier>>,
+    title_alignment: Option<TitleAlignment>,
+    title_position: Option<TitlePosition>,
     visible: Option<bool>,
     inner_margin: Option<InnerMargin>,
     border: Option<Border>,
+    border_color: Option<Color>,
+    custom_border: Option<CustomBorder>,
 }
 
 impl Default for Block {
     fn default() -> Self {
         Block {
             title: None,
+            title_color: None,
+            title_modifiers: None,
+            title_alignment: Some(TitleAlignment::Left),
+            title_position: Some(TitlePosition::Top),
             visible: Some(false),
             inner_margin: Some(InnerMargin::default()),
             border: Some(Border::Plain),
+            border_color: None,
+            custom_border: None,
         }
     }
 }
@@ -178,10 +373,36 @@ impl Block {
         String::new()
     }
 
+    pub fn get_title_style(&self) -> Style {
+        let style = Style::default().add_modifier(to_modifier(
+            self.title_modifiers.as_deref().unwrap_or_default(),
+        ));
+
+        match self.title_color {
+            Some(c) => style.fg(c),
+            None => style,
+        }
+    }
+
+    pub fn get_title_alignment(&self) -> Alignment {
+        match self.title_alignment.unwrap_or_default() {
+            TitleAlignment::Left => Alignment::Left,
+            TitleAlignment::Center => Alignment::Center,
+            TitleAlignment::Right => Alignment::Right,
+        }
+    }
+
+    pub fn get_title_position(&self) -> Position {
+        match self.title_position.unwrap_or_default() {
+            TitlePosition::Top => Position::Top,
+            TitlePosition::Bottom => Position::Bottom,
+        }
+    }
+
     pub fn get_border_type(&self) -> BorderType {
         if let Some(b) = &self.border {
             match b {
-                Border::Plain => return BorderType::Plain,
+                Border::Plain | Border::Custom => return BorderType::Plain,
                 Border::Rounded => return BorderType::Rounded,
                 Border::Double => return BorderType::Double,
                 Border::Thick => return BorderType::Thick,
@@ -191,6 +412,22 @@ impl Block {
         BorderType::Plain
     }
 
+    /// Returns the set of glyphs making up a custom border, or `None` if the
+    /// border isn't custom.
+    pub fn get_border_set(&self) -> Option<border::Set> {
+        match self.border {
+            Some(Border::Custom) => self.custom_border.map(|c| c.0),
+            _ => None,
+        }
+    }
+
+    pub fn get_border_style(&self) -> Style {
+        match self.border_color {
+            Some(c) => Style::default().fg(c),
+            None => Style::default(),
+        }
+    }
+
     pub fn is_visible(&self) -> bool {
         if let Some(v) = self.visible {
             return v;
@@ -223,6 +460,8 @@ pub struct Bar {
     symbol_close: Option<char>,
     hide_delimiters: Option<bool>,
     visible: Option<bool>,
+    filled_color: Option<Color>,
+    empty_color: Option<Color>,
 }
 
 impl Default for Bar {
@@ -233,6 +472,8 @@ impl Default for Bar {
             symbol_close: Some(')'),
             hide_delimiters: None,
             visible: None,
+            filled_color: None,
+            empty_color: None,
         }
     }
 }
@@ -286,23 +527,41 @@ impl Bar {
 
         false
     }
+    /// Returns the color of the filled part of the bars, which defaults to
+    /// the color of their key.
+    pub fn get_filled_color(&self, key_color: Color) -> Color {
+        self.filled_color.unwrap_or(key_color)
+    }
+
+    /// Returns the color of the empty part of the bars, if it differs from
+    /// that of the values.
+    pub fn get_empty_color(&self) -> Option<Color> {
+        self.empty_color
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Keys {
     pub host: Option<String>,
     pub kernel: Option<String>,
+    pub init: Option<String>,
     pub battery: Option<String>,
     pub os: Option<String>,
     pub de: Option<String>,
     pub wm: Option<String>,
     pub distro: Option<String>,
     pub terminal: Option<String>,
+    pub users: Option<String>,
+    pub session: Option<String>,
     pub shell: Option<String>,
     pub packages: Option<String>,
     pub uptime: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+    pub datetime: Option<String>,
     pub memory: Option<String>,
     pub machine: Option<String>,
+    pub virtualization: Option<String>,
     pub local_ip: Option<String>,
     pub backlight: Option<String>,
     pub resolution: Option<String>,
@@ -310,6 +569,11 @@ pub struct Keys {
     pub cpu: Option<String>,
     pub gpu: Option<String>,
     pub disk_space: Option<String>,
+    pub swap: Option<String>,
+    pub load_average: Option<String>,
+    pub temperature: Option<String>,
+    pub network: Option<String>,
+    pub throughput: Option<String>,
 }
 
 impl Default for Keys {
@@ -317,17 +581,24 @@ impl Default for Keys {
         Self {
             host: Some(String::from("Host")),
             kernel: Some(String::from("Kernel")),
+            init: Some(String::from("Init")),
             battery: Some(String::from("Battery")),
             os: Some(String::from("OS")),
             de: Some(String::from("DE")),
             wm: Some(String::from("WM")),
             distro: Some(String::from("Distro")),
             terminal: Some(String::from("Terminal")),
+            users: Some(String::from("Users")),
+            session: Some(String::from("Session")),
             shell: Some(String::from("Shell")),
             packages: Some(String::from("Packages")),
             uptime: Some(String::from("Uptime")),
+            locale: Some(String::from("Locale")),
+            timezone: Some(String::from("Timezone")),
+            datetime: Some(String::from("Date")),
             memory: Some(String::from("Memory")),
             machine: Some(String::from("Machine")),
+            virtualization: Some(String::from("Virtualization")),
             local_ip: Some(String::from("Local IP")),
             backlight: Some(String::from("Brightness")),
             resolution: Some(String::from("Resolution")),
@@ -335,6 +606,11 @@ impl Default for Keys {
             cpu: Some(String::from("CPU")),
             gpu: Some(String::from("GPU")),
             disk_space: Some(String::from("Disk Space")),
+            swap: Some(String::from("Swap")),
+            load_average: Some(String::from("Load Avg")),
+            temperature: Some(String::from("Temp")),
+            network: Some(String::from("Network")),
+            throughput: Some(String::from("Throughput")),
         }
     }
 }
@@ -356,6 +632,14 @@ impl Keys {
         "Kernel"
     }
 
+    pub fn get_init(&self) -> &str {
+        if let Some(k) = &self.init {
+            return k;
+        }
+
+        "Init"
+    }
+
     pub fn get_battery(&self) -> &str {
         if let Some(b) = &self.battery {
             return b;
@@ -404,6 +688,22 @@ impl Keys {
         "Terminal"
     }
 
+    pub fn get_users(&self) -> &str {
+        if let Some(k) = &self.users {
+            return k;
+        }
+
+        "Users"
+    }
+
+    pub fn get_session(&self) -> &str {
+        if let Some(k) = &self.session {
+            return k;
+        }
+
+        "Session"
+    }
+
     pub fn get_shell(&self) -> &str {
         if let Some(s) = &self.shell {
             return s;
@@ -428,6 +728,30 @@ impl Keys {
         "Uptime"
     }
 
+    pub fn get_locale(&self) -> &str {
+        if let Some(k) = &self.locale {
+            return k;
+        }
+
+        "Locale"
+    }
+
+    pub fn get_timezone(&self) -> &str {
+        if let Some(k) = &self.timezone {
+            return k;
+        }
+
+        "Timezone"
+    }
+
+    pub fn get_datetime(&self) -> &str {
+        if let Some(k) = &self.datetime {
+            return k;
+        }
+
+        "Date"
+    }
+
     pub fn get_memory(&self) -> &str {
         if let Some(m) = &self.memory {
             return m;
@@ -444,6 +768,14 @@ impl Keys {
         "Machine"
     }
 
+    pub fn get_virtualization(&self) -> &str {
+        if let Some(k) = &self.virtualization {
+            return k;
+        }
+
+        "Virtualization"
+    }
+
     pub fn get_local_ip(&self) -> &str {
         if let Some(l) = &self.local_ip {
             return l;
@@ -499,4 +831,139 @@ impl Keys {
 
         "Disk Space"
     }
+
+    pub fn get_swap(&self) -> &str {
+        if let Some(k) = &self.swap {
+            return k;
+        }
+
+        "Swap"
+    }
+
+    pub fn get_load_average(&self) -> &str {
+        if let Some(k) = &self.load_average {
+            return k;
+        }
+
+        "Load Avg"
+    }
+
+    pub fn get_temperature(&self) -> &str {
+        if let Some(k) = &self.temperature {
+            return k;
+        }
+
+        "Temp"
+    }
+
+    pub fn get_network(&self) -> &str {
+        if let Some(k) = &self.network {
+            return k;
+        }
+
+        "Network"
+    }
+
+    pub fn get_throughput(&self) -> &str {
+        if let Some(k) = &self.throughput {
+            return k;
+        }
+
+        "Throughput"
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReadoutStyle {
+    key_color: Option<Color>,
+    separator_color: Option<Color>,
+    value_color: Option<Color>,
+    key_modifiers: Option<Vec<TextModifier>>,
+    separator_modifiers: Option<Vec<TextModifier>>,
+    value_modifiers: Option<Vec<TextModifier>>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    icon: Option<String>,
+}
+
+impl ReadoutStyle {
+    pub fn get_key_color(&self) -> Option<Color> {
+        self.key_color
+    }
+
+    pub fn get_separator_color(&self) -> Option<Color> {
+        self.separator_color
+    }
+
+    pub fn get_value_color(&self) -> Option<Color> {
+        self.value_color
+    }
+
+    pub fn get_icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Returns the modifiers that are shared by both the key and the value.
+    fn get_shared_modifiers(&self) -> Modifier {
+        let mut modifiers = Modifier::empty();
+
+        if let Some(true) = self.bold {
+            modifiers |= Modifier::BOLD;
+        }
+
+        if let Some(true) = self.italic {
+            modifiers |= Modifier::ITALIC;
+        }
+
+        modifiers
+    }
+
+    pub fn get_key_modifiers(&self) -> Modifier {
+        self.get_shared_modifiers() | to_modifier(self.key_modifiers.as_deref().unwrap_or_default())
+    }
+
+    pub fn get_separator_modifiers(&self) -> Modifier {
+        to_modifier(self.separator_modifiers.as_deref().unwrap_or_default())
+    }
+
+    pub fn get_value_modifiers(&self) -> Modifier {
+        self.get_shared_modifiers()
+            | to_modifier(self.value_modifiers.as_deref().unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Styles {
+    pub host: Option<ReadoutStyle>,
+    pub kernel: Option<ReadoutStyle>,
+    pub init: Option<ReadoutStyle>,
+    pub battery: Option<ReadoutStyle>,
+    pub os: Option<ReadoutStyle>,
+    pub de: Option<ReadoutStyle>,
+    pub wm: Option<ReadoutStyle>,
+    pub distro: Option<ReadoutStyle>,
+    pub terminal: Option<ReadoutStyle>,
+    pub users: Option<ReadoutStyle>,
+    pub session: Option<ReadoutStyle>,
+    pub shell: Option<ReadoutStyle>,
+    pub packages: Option<ReadoutStyle>,
+    pub uptime: Option<ReadoutStyle>,
+    pub locale: Option<ReadoutStyle>,
+    pub timezone: Option<ReadoutStyle>,
+    pub datetime: Option<ReadoutStyle>,
+    pub memory: Option<ReadoutStyle>,
+    pub machine: Option<ReadoutStyle>,
+    pub virtualization: Option<ReadoutStyle>,
+    pub local_ip: Option<ReadoutStyle>,
+    pub backlight: Option<ReadoutStyle>,
+    pub resolution: Option<ReadoutStyle>,
+    pub cpu_load: Option<ReadoutStyle>,
+    pub cpu: Option<ReadoutStyle>,
+    pub gpu: Option<ReadoutStyle>,
+    pub disk_space: Option<ReadoutStyle>,
+    pub swap: Option<ReadoutStyle>,
+    pub load_average: Option<ReadoutStyle>,
+    pub temperature: Option<ReadoutStyle>,
+    pub network: Option<ReadoutStyle>,
+    pub throughput: Option<ReadoutStyle>,
 }
@@ -2,4 +2,6 @@ mod base;
 pub mod borders;
 pub mod color;
 pub mod components;
+pub mod modifiers;
+pub mod scheme;
 pub use base::*;
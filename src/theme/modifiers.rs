@@ -0,0 +1,97 @@
+use ratatui::style::Modifier;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextModifier {
+    Bold,
+    Dim,
+    Italic,
+    Underlined,
+    SlowBlink,
+    RapidBlink,
+    Reversed,
+    Hidden,
+    CrossedOut,
+}
+
+const VARIANTS: &[&str] = &[
+    "bold",
+    "dim",
+    "italic",
+    "underlined",
+    "slow_blink",
+    "rapid_blink",
+    "reversed",
+    "hidden",
+    "crossed_out",
+];
+
+impl TextModifier {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bold => "bold",
+            Self::Dim => "dim",
+            Self::Italic => "italic",
+            Self::Underlined => "underlined",
+            Self::SlowBlink => "slow_blink",
+            Self::RapidBlink => "rapid_blink",
+            Self::Reversed => "reversed",
+            Self::Hidden => "hidden",
+            Self::CrossedOut => "crossed_out",
+        }
+    }
+}
+
+impl From<TextModifier> for Modifier {
+    fn from(modifier: TextModifier) -> Self {
+        match modifier {
+            TextModifier::Bold => Modifier::BOLD,
+            TextModifier::Dim => Modifier::DIM,
+            TextModifier::Italic => Modifier::ITALIC,
+            TextModifier::Underlined => Modifier::UNDERLINED,
+            TextModifier::SlowBlink => Modifier::SLOW_BLINK,
+            TextModifier::RapidBlink => Modifier::RAPID_BLINK,
+            TextModifier::Reversed => Modifier::REVERSED,
+            TextModifier::Hidden => Modifier::HIDDEN,
+            TextModifier::CrossedOut => Modifier::CROSSED_OUT,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TextModifier {
+    fn deserialize<D>(deserializer: D) -> Result<TextModifier, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match &s.as_str().to_lowercase().replace(['-', ' '], "_")[..] {
+            "bold" => Ok(Self::Bold),
+            "dim" => Ok(Self::Dim),
+            "italic" => Ok(Self::Italic),
+            "underlined" | "underline" => Ok(Self::Underlined),
+            "slow_blink" | "slowblink" => Ok(Self::SlowBlink),
+            "rapid_blink" | "rapidblink" => Ok(Self::RapidBlink),
+            "reversed" => Ok(Self::Reversed),
+            "hidden" => Ok(Self::Hidden),
+            "crossed_out" | "crossedout" => Ok(Self::CrossedOut),
+            _ => Err(de::Error::unknown_variant(&s, VARIANTS)),
+        }
+    }
+}
+
+impl Serialize for TextModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Folds a list of modifiers into a single `Modifier`.
+pub fn to_modifier(modifiers: &[TextModifier]) -> Modifier {
+    modifiers
+        .iter()
+        .fold(Modifier::empty(), |acc, m| acc | Modifier::from(*m))
+}
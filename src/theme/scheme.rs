@@ -0,0 +1,341 @@
+use crate::error::Error;
+use crate::Result;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// The theme generated by `--theme-from-palette`, which maps the slots of
+/// the palette to the colors of the theme.
+const WAL_THEME: &str = r#"
+key_color = "wal:color4"
+separator_color = "wal:color3"
+value_color = "wal:foreground"
+error_color = "wal:color1"
+
+[box]
+title_color = "wal:color4"
+border_color = "wal:color8"
+
+[bar]
+filled_color = "wal:color4"
+empty_color = "wal:color8"
+
+[custom_ascii]
+color = "wal:color6"
+"#;
+
+const BASE16_THEME: &str = r#"
+key_color = "base16:base0D"
+separator_color = "base16:base0A"
+value_color = "base16:base05"
+error_color = "base16:base08"
+
+[box]
+title_color = "base16:base0D"
+border_color = "base16:base03"
+
+[bar]
+filled_color = "base16:base0D"
+empty_color = "base16:base03"
+
+[custom_ascii]
+color = "base16:base0C"
+"#;
+
+/// A color scheme read from a pywal or base16 file, which maps the name of
+/// each slot to its hexadecimal value.
+#[derive(Debug, Default)]
+pub struct ColorScheme {
+    colors: HashMap<String, String>,
+}
+
+impl ColorScheme {
+    /// Reads the `colors.json` file generated by pywal.
+    pub fn from_wal(path: &Path) -> Result<ColorScheme> {
+        let contents = fs::read_to_string(path)?;
+        let json: serde_json::Value = serde_json::from_str(&contents)
+            .map_err(|e| Error::Palette(format!("{}: {e}", path.to_string_lossy())))?;
+
+        let colors = ["special", "colors"]
+            .iter()
+            .filter_map(|table| json.get(table)?.as_object())
+            .flatten()
+            .filter_map(|(slot, color)| Some((slot.to_lowercase(), color.as_str()?.to_owned())))
+            .collect();
+
+        Ok(ColorScheme { colors })
+    }
+
+    /// Reads a base16 scheme, which is a flat YAML file of `baseXX: "rrggbb"`
+    /// entries.
+    pub fn from_base16(path: &Path) -> Result<ColorScheme> {
+        let contents = fs::read_to_string(path)?;
+        let colors = contents
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(slot, color)| {
+                // trailing comments are ignored, and the "#" is optional.
+                let color = color.split(" #").next().unwrap_or_default();
+                let color = color.trim().trim_matches(|c| c == '"' || c == '\'');
+                let color = color.trim_start_matches('#');
+                (slot.trim().to_lowercase(), format!("#{color}"))
+            })
+            .filter(|(slot, _)| slot.starts_with("base"))
+            .collect();
+
+        Ok(ColorScheme { colors })
+    }
+
+    fn get(&self, slot: &str) -> Option<&String> {
+        self.colors.get(&slot.to_lowercase())
+    }
+}
+
+/// The schemes referenced by the `wal:` and `base16:` colors of a theme,
+/// which are only read when they are first needed.
+#[derive(Debug, Default)]
+pub struct Schemes {
+    wal_path: Option<PathBuf>,
+    base16_path: Option<PathBuf>,
+    wal: Option<ColorScheme>,
+    base16: Option<ColorScheme>,
+}
+
+impl Schemes {
+    pub fn new(wal_path: Option<PathBuf>, base16_path: Option<PathBuf>) -> Schemes {
+        Schemes {
+            wal_path,
+            base16_path,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the hexadecimal value of a color such as `wal:color4` or
+    /// `base16:base0D`, or `None` if it isn't prefixed by a palette.
+    fn resolve(&mut self, color: &str) -> Option<Result<String>> {
+        let (prefix, slot) = color.split_once(':')?;
+        let scheme = match prefix.to_lowercase().as_str() {
+            "wal" => get_or_read(&mut self.wal, || {
+                let path = match &self.wal_path {
+                    Some(path) => path.to_owned(),
+                    None => dirs::cache_dir()
+                        .unwrap_or_default()
+                        .join("wal")
+                        .join("colors.json"),
+                };
+                ColorScheme::from_wal(&path)
+            }),
+            "base16" => get_or_read(&mut self.base16, || match &self.base16_path {
+                Some(path) => ColorScheme::from_base16(path),
+                None => Err(Error::Palette(String::from(
+                    "\"base16_scheme\" must be set to use base16 colors",
+                ))),
+            }),
+            _ => return None,
+        };
+
+        Some(scheme.and_then(|scheme| {
+            scheme
+                .get(slot)
+                .cloned()
+                .ok_or_else(|| Error::Palette(format!("\"{color}\" is not in the palette")))
+        }))
+    }
+
+    /// Replaces the palette colors of the color fields of the given table,
+    /// and of those of its nested tables, with their values.
+    pub fn resolve_colors(&mut self, table: &mut Table) -> Result<()> {
+        for (key, value) in table.iter_mut() {
+            self.resolve_value(value, is_color_key(key))?;
+        }
+
+        Ok(())
+    }
+
+    fn resolve_value(&mut self, value: &mut Value, is_color: bool) -> Result<()> {
+        match value {
+            Value::String(s) if is_color => {
+                if let Some(color) = self.resolve(s) {
+                    *s = color?;
+                }
+            }
+            Value::Array(values) => {
+                for value in values {
+                    self.resolve_value(value, is_color)?;
+                }
+            }
+            Value::Table(table) => self.resolve_colors(table)?,
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether the given key of a theme holds one or more colors, e.g.
+/// `key_color`, or `color` and `colors` of the `[custom_ascii]` table.
+fn is_color_key(key: &str) -> bool {
+    key == "color" || key == "colors" || key.ends_with("_color")
+}
+
+fn get_or_read<F>(scheme: &mut Option<ColorScheme>, read: F) -> Result<&ColorScheme>
+where
+    F: FnOnce() -> Result<ColorScheme>,
+{
+    if scheme.is_none() {
+        *scheme = Some(read()?);
+    }
+
+    Ok(scheme.as_ref().unwrap())
+}
+
+fn expand(path: &Value) -> Option<PathBuf> {
+    let path = path.as_str()?;
+    Some(PathBuf::from(shellexpand::tilde(path).to_string()))
+}
+
+/// Parses the contents of a theme, replacing its palette colors with their
+/// values.
+pub fn parse_theme(contents: &str) -> Result<Table> {
+    let mut table: Table = toml::from_str(contents)?;
+    let mut schemes = Schemes::new(
+        table.get("wal_colors").and_then(expand),
+        table.get("base16_scheme").and_then(expand),
+    );
+
+    schemes.resolve_colors(&mut table)?;
+    Ok(table)
+}
+
+/// Generates a theme from a pywal `colors.json` file or a base16 scheme,
+/// depending on the extension of the file.
+pub fn theme_from_palette(path: &Path) -> Result<Table> {
+    let mut table: Table;
+    let mut schemes = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => {
+            table = toml::from_str(WAL_THEME)?;
+            Schemes::new(Some(path.to_owned()), None)
+        }
+        Some("yaml" | "yml") => {
+            table = toml::from_str(BASE16_THEME)?;
+            Schemes::new(None, Some(path.to_owned()))
+        }
+        _ => {
+            return Err(Error::Palette(format!(
+                "{}: expected a pywal \".json\" or a base16 \".yaml\" file",
+                path.to_string_lossy()
+            )))
+        }
+    };
+
+    schemes.resolve_colors(&mut table)?;
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_colors() {
+        let dir = std::env::temp_dir().join(format!("macchina-scheme-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let wal = dir.join("colors.json");
+        let base16 = dir.join("scheme.yaml");
+        fs::write(
+            &wal,
+            r##"{"special": {"foreground": "#c5c8c6"}, "colors": {"color4": "#81a2be"}}"##,
+        )
+        .unwrap();
+        fs::write(
+            &base16,
+            "scheme: \"Tomorrow\"\nbase0D: \"81a2be\"\nbase08: 'cc6666'\n",
+        )
+        .unwrap();
+
+        let mut schemes = Schemes::new(Some(wal), Some(base16));
+        let mut table: Table = toml::from_str(
+            r#"
+            key_color = "wal:color4"
+            value_color = "wal:Foreground"
+            separator_color = "Yellow"
+
+            [box]
+            title_color = "base16:base0d"
+            border_color = "base16:base08"
+            "#,
+        )
+        .unwrap();
+
+        schemes.resolve_colors(&mut table).unwrap();
+        assert_eq!(table["key_color"].as_str(), Some("#81a2be"));
+        assert_eq!(table["value_color"].as_str(), Some("#c5c8c6"));
+        assert_eq!(table["separator_color"].as_str(), Some("Yellow"));
+        assert_eq!(table["box"]["title_color"].as_str(), Some("#81a2be"));
+        assert_eq!(table["box"]["border_color"].as_str(), Some("#cc6666"));
+
+        let mut table: Table = toml::from_str(
+            r#"
+            separator = "wal:color4"
+
+            [header]
+            text = "base16:base08"
+            color = "base16:base08"
+
+            [custom_ascii]
+            colors = ["wal:color4", "Red"]
+            "#,
+        )
+        .unwrap();
+
+        schemes.resolve_colors(&mut table).unwrap();
+        assert_eq!(table["separator"].as_str(), Some("wal:color4"));
+        assert_eq!(table["header"]["text"].as_str(), Some("base16:base08"));
+        assert_eq!(table["header"]["color"].as_str(), Some("#cc6666"));
+        assert_eq!(
+            table["custom_ascii"]["colors"],
+            Value::Array(vec![Value::from("#81a2be"), Value::from("Red")])
+        );
+
+        let mut table: Table = toml::from_str(r#"key_color = "wal:color42""#).unwrap();
+        assert!(schemes.resolve_colors(&mut table).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_theme_from_palette() {
+        use crate::theme::Theme;
+        use ratatui::style::Color;
+        use serde::Deserialize;
+
+        let dir = std::env::temp_dir().join(format!("macchina-palette-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let base16 = dir.join("scheme.yaml");
+        fs::write(
+            &base16,
+            "base03: \"969896\"\nbase05: \"c5c8c6\"\nbase08: \"cc6666\"\n\
+             base0A: \"f0c674\"\nbase0C: \"8abeb7\"\nbase0D: \"81a2be\"\n",
+        )
+        .unwrap();
+
+        let table = theme_from_palette(&base16).unwrap();
+        assert_eq!(table["bar"]["filled_color"].as_str(), Some("#81a2be"));
+        assert_eq!(table["bar"]["empty_color"].as_str(), Some("#969896"));
+
+        let theme = Theme::deserialize(table).unwrap();
+        assert_eq!(
+            theme.get_bar().get_filled_color(Color::Reset),
+            Color::Rgb(0x81, 0xa2, 0xbe)
+        );
+        assert_eq!(
+            theme.get_bar().get_empty_color(),
+            Some(Color::Rgb(0x96, 0x98, 0x96))
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,5 +1,5 @@
 use crate::data::{Readout, ReadoutKey};
-use crate::theme::components::{Palette, PaletteType};
+use crate::theme::components::{OnError, Palette, PaletteType, SectionLayout};
 use crate::theme::Theme;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Margin, Rect};
@@ -8,10 +8,44 @@ use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Paragraph, Widget};
 use std::collections::HashMap;
 
+/// A group of readouts, optionally preceded by a title.
+#[derive(Debug, Clone)]
+pub struct ReadoutSection<'a> {
+    title: Option<String>,
+    items: Vec<Readout<'a>>,
+}
+
+impl<'a> ReadoutSection<'a> {
+    pub fn new<T>(title: Option<String>, items: T) -> ReadoutSection<'a>
+    where
+        T: Into<Vec<Readout<'a>>>,
+    {
+        ReadoutSection {
+            title,
+            items: items.into(),
+        }
+    }
+
+    pub fn items(&self) -> &[Readout<'a>] {
+        &self.items
+    }
+
+    /// Returns whether the section has nothing to display, i.e. whether all
+    /// of its readouts failed and failures are hidden.
+    fn is_empty(&self, theme: &Theme) -> bool {
+        match theme.get_on_error() {
+            OnError::Hide => !self.items.iter().any(|f| f.1.is_ok()),
+            _ => self.items.is_empty(),
+        }
+    }
+}
+
 pub struct ReadoutList<'a> {
     block: Option<Block<'a>>,
     style: Style,
-    items: Vec<Readout<'a>>,
+    sections: Vec<ReadoutSection<'a>>,
+    header: Option<Text<'a>>,
+    footer: Option<Text<'a>>,
     theme: &'a Theme,
     block_inner_margin: Margin,
 }
@@ -24,7 +58,9 @@ impl<'a> ReadoutList<'a> {
         ReadoutList {
             block: None,
             style: Style::default(),
-            items: items.into(),
+            sections: vec![ReadoutSection::new(None, items)],
+            header: None,
+            footer: None,
             theme,
             block_inner_margin: Margin {
                 horizontal: 0,
@@ -39,7 +75,25 @@ impl<'a> ReadoutList<'a> {
     }
 
     pub fn add_item(mut self, item: Readout<'a>) -> ReadoutList<'a> {
-        self.items.push(item);
+        match self.sections.last_mut() {
+            Some(section) => section.items.push(item),
+            None => self.sections.push(ReadoutSection::new(None, vec![item])),
+        }
+        self
+    }
+
+    pub fn sections(mut self, sections: Vec<ReadoutSection<'a>>) -> ReadoutList<'a> {
+        self.sections = sections;
+        self
+    }
+
+    pub fn header(mut self, header: Text<'a>) -> ReadoutList<'a> {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn footer(mut self, footer: Text<'a>) -> ReadoutList<'a> {
+        self.footer = Some(footer);
         self
     }
 
@@ -62,46 +116,261 @@ impl<'a> ReadoutList<'a> {
 impl<'a> Widget for ReadoutList<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
-        let list_area = match &self.block {
-            Some(b) => {
-                let inner_area = b.inner(area);
-                inner_area.inner(self.block_inner_margin)
-            }
-            None => area,
-        };
 
-        if list_area.width < 1 || list_area.height < 1 {
+        if self.sections.iter().all(|s| s.is_empty(self.theme)) {
             return;
         }
 
-        if self.items.is_empty() {
+        let keys = self.keys_to_text(self.theme);
+        let align_keys = self.theme.get_sections().align_keys();
+        let max_key_width = if align_keys {
+            Some(Self::get_max_key_width(keys.values()))
+        } else {
+            None
+        };
+
+        match self.theme.get_sections().get_layout() {
+            SectionLayout::Box if self.block.is_some() && self.sections.len() > 1 => {
+                self.render_boxed_sections(area, buf, &keys, max_key_width)
+            }
+            _ => self.render_sections(area, buf, &keys, max_key_width),
+        }
+    }
+}
+
+impl<'a> ReadoutList<'a> {
+    /// Renders every section inside of the same block, separating them with
+    /// their heading.
+    fn render_sections(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        keys: &HashMap<ReadoutKey, Text>,
+        max_key_width: Option<usize>,
+    ) {
+        let list_area = self.get_list_area(area);
+        if list_area.width < 1 || list_area.height < 1 {
             return;
         }
 
+        let gap = self.theme.get_sections().get_gap();
         let mut height = 0;
-        let keys = self.keys_to_text(self.theme);
-        let max_key_width = Self::get_max_key_width(&keys);
-        let themed_separator = Self::get_themed_separator(
-            self.theme.get_separator(),
-            &self.theme.get_separator_color(),
+        let mut max_line_width: u16 = 0;
+
+        if let Some(header) = &self.header {
+            let header_width = self.render_text(header, &list_area, height, buf);
+            max_line_width = max_line_width.max(header_width);
+            height += header.height() as u16;
+        }
+
+        for (i, section) in self
+            .sections
+            .iter()
+            .filter(|s| !s.is_empty(self.theme))
+            .enumerate()
+        {
+            if i > 0 {
+                height += gap;
+            }
+
+            if let Some(title) = &section.title {
+                let heading = self.render_heading(title, &list_area, height, buf);
+                max_line_width = max_line_width.max(heading);
+                height += 1;
+            }
+
+            let max_key_width =
+                max_key_width.unwrap_or_else(|| Self::get_section_key_width(keys, section));
+            let (section_height, section_width) =
+                self.render_items(&section.items, &list_area, height, keys, max_key_width, buf);
+
+            height += section_height;
+            max_line_width = max_line_width.max(section_width);
+        }
+
+        if let Some(footer) = &self.footer {
+            let footer_width = self.render_text(footer, &list_area, height, buf);
+            max_line_width = max_line_width.max(footer_width);
+            height += footer.height() as u16;
+        }
+
+        self.print_palette(
+            buf,
+            &list_area,
+            &mut height,
+            &mut max_line_width,
+            self.theme.get_palette(),
+        );
+
+        Self::render_block(
+            self.block.clone(),
+            buf,
+            area.x,
+            area.y,
+            height,
+            max_line_width,
+            &self.block_inner_margin,
         );
+    }
 
+    /// Renders every section inside of its own block, titled after the section.
+    fn render_boxed_sections(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        keys: &HashMap<ReadoutKey, Text>,
+        max_key_width: Option<usize>,
+    ) {
+        let gap = self.theme.get_sections().get_gap();
+        let sections: Vec<_> = self
+            .sections
+            .iter()
+            .filter(|s| !s.is_empty(self.theme))
+            .collect();
+        let mut boxes: Vec<(&ReadoutSection, u16, u16, u16)> = Vec::with_capacity(sections.len());
+        let mut y = area.y;
+
+        // like in a single block, the header and footer sit inside of the
+        // first and last boxes respectively.
+        for (i, section) in sections.iter().enumerate() {
+            // the area given to the list only accounts for the height of the
+            // ASCII art, which stacked boxes can easily outgrow.
+            let section_area = Rect {
+                x: area.x,
+                y,
+                width: area.width,
+                height: buf.area.bottom().saturating_sub(y),
+            };
+
+            let list_area = self.get_list_area(section_area);
+            if list_area.width < 1 || list_area.height < 1 {
+                break;
+            }
+
+            let mut height = 0;
+            let mut max_line_width: u16 = 0;
+
+            if let (0, Some(header)) = (i, &self.header) {
+                max_line_width = self.render_text(header, &list_area, height, buf);
+                height += header.height() as u16;
+            }
+
+            let max_key_width =
+                max_key_width.unwrap_or_else(|| Self::get_section_key_width(keys, section));
+            let (section_height, section_width) =
+                self.render_items(&section.items, &list_area, height, keys, max_key_width, buf);
+            height += section_height;
+            max_line_width = max_line_width.max(section_width);
+
+            if i + 1 == sections.len() {
+                if let Some(footer) = &self.footer {
+                    let footer_width = self.render_text(footer, &list_area, height, buf);
+                    max_line_width = max_line_width.max(footer_width);
+                    height += footer.height() as u16;
+                }
+
+                self.print_palette(
+                    buf,
+                    &list_area,
+                    &mut height,
+                    &mut max_line_width,
+                    self.theme.get_palette(),
+                );
+            }
+
+            boxes.push((section, y, height, max_line_width));
+            y += height + 2 + self.block_inner_margin.vertical * 2 + gap;
+        }
+
+        // Boxes are drawn once their contents have been measured, so that
+        // they can share the same width when keys are aligned across sections.
+        let widest = boxes.iter().map(|b| b.3).max().unwrap_or_default();
+        for (section, y, height, width) in boxes {
+            let mut block = self.block.clone();
+            if let (Some(b), Some(title)) = (block.as_mut(), &section.title) {
+                *b = b.clone().title(title.to_owned());
+            }
+
+            let width = if max_key_width.is_some() {
+                widest
+            } else {
+                width
+            };
+            Self::render_block(
+                block,
+                buf,
+                area.x,
+                y,
+                height,
+                width,
+                &self.block_inner_margin,
+            );
+        }
+    }
+
+    /// Renders the heading of a section and returns its width.
+    fn render_heading(&self, title: &str, list_area: &Rect, y: u16, buf: &mut Buffer) -> u16 {
+        let heading = Text::styled(
+            title.to_owned(),
+            self.theme
+                .get_sections()
+                .get_heading_style(self.theme.get_key_color()),
+        );
+
+        self.render_text(&heading, list_area, y, buf)
+    }
+
+    /// Renders the given text, padded like the readouts, and returns its width.
+    fn render_text(&self, text: &Text, list_area: &Rect, y: u16, buf: &mut Buffer) -> u16 {
+        let padding = self.theme.get_padding() as u16;
+        let width = text.width() as u16;
+
+        Paragraph::new(text.to_owned()).render(
+            Rect {
+                x: list_area.x + padding,
+                y: list_area.y + y,
+                width: list_area.width.saturating_sub(padding),
+                height: text.height() as u16,
+            },
+            buf,
+        );
+
+        width + padding
+    }
+
+    /// Renders the successful readouts of a section, starting at the given
+    /// vertical offset, and returns the height and width they occupy.
+    fn render_items(
+        &self,
+        items: &[Readout],
+        list_area: &Rect,
+        offset: u16,
+        keys: &HashMap<ReadoutKey, Text>,
+        max_key_width: usize,
+        buf: &mut Buffer,
+    ) -> (u16, u16) {
+        let mut height = 0;
         let mut max_line_width: u16 = 0;
 
-        for item in self.items.iter().filter(|&f| f.1.is_ok()) {
-            //it's ok to unwrap, because we filtered out everything that is not a valid Option<T>.
-            let readout_data = item.1.as_ref().unwrap();
+        for item in items.iter() {
+            let Some(readout_data) = self.get_readout_text(item) else {
+                continue;
+            };
             let readout_key = keys.get(&item.0).unwrap();
+            let themed_separator = Self::get_themed_separator(
+                self.theme.get_separator(),
+                self.theme.separator_style(&item.0),
+            );
 
             let list_item_area = Rect {
                 x: list_area.x,
-                y: list_area.y + height,
+                y: list_area.y + offset + height,
                 width: list_area.width,
                 height: readout_data.height() as u16,
             };
 
             let constraints =
-                self.create_item_constraints(max_key_width, &themed_separator, readout_data);
+                self.create_item_constraints(max_key_width, &themed_separator, &readout_data);
             let layout = Self::create_layout(&list_item_area, &constraints);
 
             let total_line_width = constraints.iter().sum::<u16>();
@@ -121,27 +390,37 @@ impl<'a> Widget for ReadoutList<'a> {
             }
 
             layout_iter.next();
-            Paragraph::new(readout_data.to_owned()).render(*layout_iter.next().unwrap(), buf);
-            height += readout_data.height() as u16;
+            Paragraph::new(readout_data).render(*layout_iter.next().unwrap(), buf);
+            height += list_item_area.height;
         }
 
-        self.print_palette(
-            buf,
-            &list_area,
-            &mut height,
-            &mut max_line_width,
-            self.theme.get_palette(),
-        );
+        (height, max_line_width)
+    }
 
-        Self::render_block(
-            self.block,
-            buf,
-            area.x,
-            area.y,
-            height,
-            max_line_width,
-            &self.block_inner_margin,
-        );
+    /// Returns the styled text of a readout, or that of its failure depending
+    /// on how the theme handles them.
+    fn get_readout_text(&self, item: &Readout<'a>) -> Option<Text<'a>> {
+        let error_style = Style::default().fg(self.theme.get_error_color());
+
+        match (&item.1, self.theme.get_on_error()) {
+            (Ok(text), _) => Some(text.to_owned().patch_style(self.theme.value_style(&item.0))),
+            (Err(_), OnError::Hide) => None,
+            (Err(_), OnError::Placeholder) => Some(Text::styled(
+                self.theme.get_error_placeholder().to_owned(),
+                error_style,
+            )),
+            (Err(e), OnError::Message) => Some(Text::styled(e.to_string(), error_style)),
+        }
+    }
+
+    fn get_list_area(&self, area: Rect) -> Rect {
+        match &self.block {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                inner_area.inner(self.block_inner_margin)
+            }
+            None => area,
+        }
     }
 }
 
@@ -236,17 +515,36 @@ impl<'a> ReadoutList<'a> {
         *height += area.height + 1;
     }
 
-    fn keys_to_text(&self, theme: &Theme) -> HashMap<ReadoutKey, Text> {
-        let style = Style::default().fg(theme.get_key_color());
-
-        self.items
+    fn keys_to_text(&self, theme: &Theme) -> HashMap<ReadoutKey, Text<'_>> {
+        self.sections
             .iter()
-            .map(|i| (i.0, Text::styled(theme.key(&i.0).to_string(), style)))
+            .flat_map(|s| s.items.iter())
+            .map(|i| (i.0, Self::key_to_text(theme, &i.0)))
             .collect()
     }
 
-    fn get_max_key_width(keys: &HashMap<ReadoutKey, Text>) -> usize {
-        keys.iter().map(|i| i.1.width()).max().unwrap()
+    /// Returns the styled key of a readout, prefixed by its icon if one was
+    /// specified. The icon is part of the returned `Text`, so that its width
+    /// is accounted for when aligning the keys.
+    fn key_to_text(theme: &Theme, readout_key: &ReadoutKey) -> Text<'static> {
+        let style = theme.key_style(readout_key);
+        let key = theme.key(readout_key).to_string();
+
+        match theme.icon(readout_key) {
+            Some(icon) if !icon.is_empty() => Text::from(Line::from(vec![
+                Span::styled(format!("{icon} "), style),
+                Span::styled(key, style),
+            ])),
+            _ => Text::styled(key, style),
+        }
+    }
+
+    fn get_max_key_width<'k>(keys: impl Iterator<Item = &'k Text<'k>>) -> usize {
+        keys.map(|k| k.width()).max().unwrap_or_default()
+    }
+
+    fn get_section_key_width(keys: &HashMap<ReadoutKey, Text>, section: &ReadoutSection) -> usize {
+        Self::get_max_key_width(section.items.iter().filter_map(|i| keys.get(&i.0)))
     }
 
     fn render_block(
@@ -271,8 +569,8 @@ impl<'a> ReadoutList<'a> {
         }
     }
 
-    fn get_themed_separator(separator: &'a str, sep_color: &Color) -> Text<'a> {
-        Text::styled(separator, Style::default().fg(*sep_color))
+    fn get_themed_separator(separator: &'a str, style: Style) -> Text<'a> {
+        Text::styled(separator, style)
     }
 }
 
@@ -326,3 +624,79 @@ impl<'a> ReadoutList<'a> {
         layout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libmacchina::traits::ReadoutError;
+
+    #[test]
+    fn test_failed_readouts_use_error_color() {
+        let theme: Theme = toml::from_str(
+            r#"
+            value_color = "Red"
+            error_color = "Green"
+            on_error = "placeholder"
+            error_placeholder = "N/A"
+            "#,
+        )
+        .unwrap();
+        let items = vec![
+            Readout::new(ReadoutKey::Kernel, "Linux"),
+            Readout::new_err(ReadoutKey::GPU, ReadoutError::Other(String::from("no GPU"))),
+        ];
+
+        let area = Rect::new(0, 0, 40, 2);
+        let mut buf = Buffer::empty(area);
+        ReadoutList::new(items, &theme).render(area, &mut buf);
+
+        let fg_of = |line: u16, symbol: &str| {
+            (0..area.width)
+                .map(|x| &buf[(x, line)])
+                .find(|cell| cell.symbol() == symbol)
+                .map(|cell| cell.fg)
+        };
+        assert_eq!(fg_of(0, "L"), Some(Color::Red));
+        assert_eq!(fg_of(1, "N"), Some(Color::Green));
+    }
+
+    #[test]
+    fn test_header_and_footer_inside_boxes() {
+        use ratatui::widgets::Borders;
+
+        for layout in ["heading", "box"] {
+            let theme: Theme =
+                toml::from_str(&format!("[sections]\nlayout = \"{layout}\"")).unwrap();
+            let sections = vec![
+                ReadoutSection::new(
+                    Some(String::from("Software")),
+                    vec![Readout::new(ReadoutKey::Kernel, "Linux")],
+                ),
+                ReadoutSection::new(
+                    Some(String::from("Hardware")),
+                    vec![Readout::new(ReadoutKey::Memory, "4 GiB")],
+                ),
+            ];
+
+            let area = Rect::new(0, 0, 40, 12);
+            let mut buf = Buffer::empty(area);
+            ReadoutList::new(vec![], &theme)
+                .sections(sections)
+                .header(Text::raw("user@host"))
+                .footer(Text::raw("footer"))
+                .block(Block::default().borders(Borders::ALL))
+                .render(area, &mut buf);
+
+            let rows: Vec<String> = (0..area.height)
+                .map(|y| (0..area.width).map(|x| buf[(x, y)].symbol()).collect())
+                .collect();
+            let header = rows.iter().position(|r| r.contains("user@host")).unwrap();
+            let footer = rows.iter().position(|r| r.contains("footer")).unwrap();
+
+            assert!(rows[header - 1].starts_with('┌'), "{layout}: {rows:#?}");
+            assert!(rows[header].starts_with('│'), "{layout}: {rows:#?}");
+            assert!(rows[footer].starts_with('│'), "{layout}: {rows:#?}");
+            assert!(rows[footer + 1].starts_with('└'), "{layout}: {rows:#?}");
+        }
+    }
+}